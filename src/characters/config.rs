@@ -5,6 +5,9 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::characters::combat::{DamageType, Weapon};
+use crate::map::spatial::TileSize;
+
 // HashMap to store AnimationType as a key
 // Serialize and Deserialize to turn structs into .ron text
 // added Attack and Death animation types
@@ -40,6 +43,35 @@ pub struct CharacterEntry {
     pub tile_size: u32,
     pub atlas_columns: usize,
     pub animations: HashMap<AnimationType, AnimationDefinition>,
+    // Elemental affinity for enemies of this character type; absent (or Physical) means plain hits.
+    #[serde(default)]
+    pub damage_type: Option<DamageType>,
+    // Player-selectable abilities (number keys 1-4 in combat). Absent or empty falls back to a
+    // single basic strike built from this entry's stats; see combat::sync_player_stats.
+    #[serde(default)]
+    pub abilities: Vec<Weapon>,
+    // Faction this character belongs to (see characters::faction). Absent falls back to
+    // "player"/"wildlife" depending on whether the entry ends up on a Player or an Enemy.
+    #[serde(default)]
+    pub faction: Option<String>,
+    // Player progression pool sizing (see combat::Progression); lets a caster archetype ship a
+    // bigger mana pool/faster regen than a fighter archetype without changing code.
+    #[serde(default = "default_max_mana")]
+    pub max_mana: f32,
+    #[serde(default = "default_mana_regen")]
+    pub mana_regen: f32,
+    // Collision/spawn footprint in tiles (e.g. a 2x2 boss). Absent falls back to 1x1, matching
+    // every character authored before multi-tile support existed. See map::spatial::TileSize.
+    #[serde(default)]
+    pub footprint: TileSize,
+}
+
+fn default_max_mana() -> f32 {
+    20.0
+}
+
+fn default_mana_regen() -> f32 {
+    2.0
 }
 
 impl CharacterEntry {
@@ -0,0 +1,111 @@
+// Data-driven named visual effects (explosions, etc.), spawned at an entity's transform and
+// ticked until their lifetime elapses. A plain sprite/atlas sibling to characters::particles'
+// bevy_hanabi GPU bursts — this one is for effects design wants to author by dropping in a
+// spritesheet (named in effects.ron) rather than tuning a particle graph in code.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EffectDefinition {
+    pub texture_path: String,
+    pub tile_size: u32,
+    pub atlas_columns: usize,
+    pub frame_count: usize,
+    pub frame_time: f32,
+    pub lifetime_secs: f32,
+    pub base_size: f32,
+    // Whether the effect drifts along with whatever spawned it (e.g. a knockback hit) instead of
+    // staying put (e.g. a death explosion).
+    #[serde(default)]
+    pub inherit_velocity: bool,
+}
+
+/// Named effect registry, loaded from effects.ron alongside CharactersList/ReactionTable.
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+pub struct EffectsRegistry {
+    effects: HashMap<String, EffectDefinition>,
+}
+
+#[derive(Resource)]
+pub struct EffectsRegistryHandle(pub Handle<EffectsRegistry>);
+
+pub fn load_effects_registry(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(EffectsRegistryHandle(asset_server.load("effects.ron")));
+}
+
+/// A spawned, playing instance of a named effect.
+#[derive(Component)]
+pub struct VisualEffect {
+    frame_time: f32,
+    frame_count: usize,
+    elapsed: f32,
+    lifetime: f32,
+    velocity: Vec2,
+}
+
+/// Spawn `name` from the registry at `at`, scaled by `size_scale` (e.g. an enemy's ENEMY_SCALE)
+/// and inheriting `velocity` if the definition asks for it. No-ops if the registry hasn't loaded
+/// yet or doesn't know `name`, so a missing/unloaded effects.ron degrades gracefully instead of
+/// panicking. Reusable by any system that wants to fire a named effect — enemy deaths today
+/// (see combat::handle_enemy_death_cleanup), pip pickups and combat strikes are natural future
+/// callers.
+pub fn spawn_named_effect(
+    commands: &mut Commands,
+    registry: Option<&EffectsRegistry>,
+    asset_server: &AssetServer,
+    atlas_layouts: &mut Assets<TextureAtlasLayout>,
+    name: &str,
+    at: Vec3,
+    size_scale: f32,
+    velocity: Vec2,
+) {
+    let Some(registry) = registry else { return; };
+    let Some(def) = registry.effects.get(name) else { return; };
+
+    let layout = atlas_layouts.add(TextureAtlasLayout::from_grid(
+        UVec2::splat(def.tile_size),
+        def.atlas_columns as u32,
+        1,
+        None,
+        None,
+    ));
+    let texture: Handle<Image> = asset_server.load(&def.texture_path);
+    let sprite = Sprite::from_atlas_image(texture, TextureAtlas { layout, index: 0 });
+
+    commands.spawn((
+        sprite,
+        Transform::from_translation(at).with_scale(Vec3::splat(def.base_size * size_scale)),
+        VisualEffect {
+            frame_time: def.frame_time.max(0.001),
+            frame_count: def.frame_count.max(1),
+            elapsed: 0.0,
+            lifetime: def.lifetime_secs,
+            velocity: if def.inherit_velocity { velocity } else { Vec2::ZERO },
+        },
+    ));
+}
+
+/// Advance each playing effect's frame/position and despawn it once its lifetime elapses.
+pub fn tick_effects(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut VisualEffect, &mut Transform, &mut Sprite)>,
+) {
+    for (entity, mut effect, mut transform, mut sprite) in query.iter_mut() {
+        effect.elapsed += time.delta_secs();
+        if effect.elapsed >= effect.lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        if effect.velocity != Vec2::ZERO {
+            transform.translation += effect.velocity.extend(0.0) * time.delta_secs();
+        }
+        if let Some(atlas) = sprite.texture_atlas.as_mut() {
+            let frame = ((effect.elapsed / effect.frame_time) as usize).min(effect.frame_count - 1);
+            atlas.index = frame;
+        }
+    }
+}
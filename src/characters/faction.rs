@@ -0,0 +1,83 @@
+// Faction-based reaction system, replacing "proximity to any Enemy starts a fight" with an
+// explicit Hostile/Neutral/Friendly lookup between the two parties' factions.
+//
+// Factions are tagged per-entity from CharacterEntry.faction (so the same characters.ron that
+// defines a character's stats/animations also decides who it's hostile to) rather than a new
+// per-spawn field, via the ensure_faction backfill below. The Hostile/Neutral/Friendly table
+// itself is a separate RON asset, loaded the same way CharactersList is, so design can retune
+// alliances without touching code.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::characters::config::CharacterEntry;
+use crate::characters::movement::Player;
+use crate::characters::npc::Enemy;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Reaction {
+    Hostile,
+    Neutral,
+    Friendly,
+}
+
+/// Which faction an entity belongs to. Tagged from CharacterEntry.faction by ensure_faction.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct Faction(pub String);
+
+/// Reaction lookup between faction pairs, loaded from a RON asset alongside CharactersList.
+/// Unlisted pairs fall back to Neutral (see `reaction`), so adding a new faction to
+/// characters.ron doesn't start a fight until someone explicitly marks it Hostile.
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+pub struct ReactionTable {
+    reactions: HashMap<(String, String), Reaction>,
+}
+
+impl Default for ReactionTable {
+    // Baseline so the game is still playable before a real reactions.ron is authored: the
+    // default "wildlife" faction (see ensure_faction) is hostile to the player, everything else
+    // is left to fall back to Neutral.
+    fn default() -> Self {
+        let mut reactions = HashMap::new();
+        reactions.insert(("player".to_string(), "wildlife".to_string()), Reaction::Hostile);
+        Self { reactions }
+    }
+}
+
+impl ReactionTable {
+    /// Looks up the reaction between two factions, checking both orderings since hostility is
+    /// symmetric, and defaulting unconfigured pairs to Neutral.
+    pub fn reaction(&self, a: &str, b: &str) -> Reaction {
+        self.reactions
+            .get(&(a.to_string(), b.to_string()))
+            .or_else(|| self.reactions.get(&(b.to_string(), a.to_string())))
+            .copied()
+            .unwrap_or(Reaction::Neutral)
+    }
+}
+
+#[derive(Resource)]
+pub struct ReactionTableHandle(pub Handle<ReactionTable>);
+
+pub fn load_reaction_table(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(ReactionTableHandle(asset_server.load("reactions.ron")));
+}
+
+/// Tag any Player/Enemy with a CharacterEntry but no Faction yet, mirroring the ensure_* backfill
+/// pattern used elsewhere (e.g. movement::ensure_player_physics_body) for components that would
+/// otherwise need editing the spawn systems directly. Falls back to "player" / "wildlife" when
+/// the entry doesn't declare an explicit faction.
+pub fn ensure_faction(
+    mut commands: Commands,
+    players: Query<(Entity, &CharacterEntry), (With<Player>, Without<Faction>)>,
+    enemies: Query<(Entity, &CharacterEntry), (With<Enemy>, Without<Faction>)>,
+) {
+    for (entity, entry) in players.iter() {
+        commands.entity(entity).insert(Faction(entry.faction.clone().unwrap_or_else(|| "player".to_string())));
+    }
+    for (entity, entry) in enemies.iter() {
+        commands.entity(entity).insert(Faction(entry.faction.clone().unwrap_or_else(|| "wildlife".to_string())));
+    }
+}
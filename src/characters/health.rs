@@ -4,9 +4,9 @@
 use bevy::prelude::*;
 
 use crate::characters::movement::Player;
-use crate::characters::combat::{CombatStats, GameOutcome};
-use crate::map::collision::{NonWalkable, Water, nonwalkable_half_extent, water_half_extent};
+use crate::characters::combat::CombatStats;
 use crate::map::generate::{map_pixel_dimensions, TILE_SIZE};
+use crate::map::spatial::SpatialIndex;
 
 // Small red pickups that restore player HP when collected
 #[derive(Component)]
@@ -18,18 +18,17 @@ pub struct HealthPipTracker {
     pub spawned: bool,
 }
 
-// Spawn a handful of health pips on valid ground (not on water)
+// Spawn a handful of health pips on valid ground (not on water).
+// Scheduled with run_if(in_state(GamePhase::Exploring)).
 pub fn spawn_health_pips_once(
     mut commands: Commands,
     mut tracker: ResMut<HealthPipTracker>,
-    blocking_tiles: Query<&GlobalTransform, With<NonWalkable>>,
-    water_tiles: Query<&GlobalTransform, With<Water>>,
-    outcome: Res<GameOutcome>,
+    spatial_index: Res<SpatialIndex>,
 ) {
-    if tracker.spawned || !matches!(*outcome, GameOutcome::None) { return; }
-    // Ensure terrain has spawned before placing pips so we can avoid water properly
-    // If no water tiles are present yet, defer spawning to a later frame
-    if water_tiles.iter().next().is_none() {
+    if tracker.spawned { return; }
+    // Ensure terrain has spawned (and the spatial index built from it) before placing pips so we
+    // can avoid water properly. Defer spawning to a later frame otherwise.
+    if !spatial_index.is_built() {
         return;
     }
 
@@ -38,25 +37,9 @@ pub fn spawn_health_pips_once(
     let map_size = map_pixel_dimensions();
     let half = map_size * 0.5;
 
-    // helper: returns true if point collides a solid or water tile
-    let would_collide = |point: Vec2| -> bool {
-        let half_solid = nonwalkable_half_extent();
-        for gt in blocking_tiles.iter() {
-            let pos = gt.translation().truncate();
-            let dx = (point.x - pos.x).abs();
-            let dy = (point.y - pos.y).abs();
-            if dx <= half_solid && dy <= half_solid { return true; }
-        }
-        let half_water = water_half_extent();
-        for gt in water_tiles.iter() {
-            let pos = gt.translation().truncate();
-            let dx = (point.x - pos.x).abs();
-            let dy = (point.y - pos.y).abs();
-            // Use <= so pips never spawn on water or touching its bounds
-            if dx <= half_water && dy <= half_water { return true; }
-        }
-        false
-    };
+    // helper: returns true if point collides a solid or water tile, backed by the O(1) spatial
+    // index instead of scanning every NonWalkable/Water GlobalTransform per candidate point.
+    let would_collide = |point: Vec2| -> bool { spatial_index.is_blocked(point) };
 
     // spawn 6 pips
     let count = 6usize;
@@ -82,14 +65,13 @@ pub fn spawn_health_pips_once(
     tracker.spawned = true;
 }
 
-// Collect pips when the player walks over them and heal
+// Collect pips when the player walks over them and heal.
+// Scheduled with run_if(in_state(GamePhase::Exploring)).
 pub fn collect_health_pips(
     mut commands: Commands,
     mut player_q: Query<(&GlobalTransform, &mut CombatStats), With<Player>>,
     pips_q: Query<(Entity, &GlobalTransform), With<HealthPip>>,
-    outcome: Res<GameOutcome>,
 ) {
-    if !matches!(*outcome, GameOutcome::None) { return; }
     let Ok((p_tf, mut stats)) = player_q.single_mut() else { return; };
     let p = p_tf.translation().truncate();
     let radius = TILE_SIZE * 0.4;
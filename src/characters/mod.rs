@@ -10,27 +10,70 @@ pub mod combat;
 pub mod health;
 pub mod ui;
 
+// added A* pathfinding so enemies chase the player instead of standing still
+pub mod pathfinding;
+
+// deterministic-simulation groundwork for an eventual rollback co-op mode (see netcode.rs)
+pub mod netcode;
+
+// particle bursts for jumps, hits, and deaths
+pub mod particles;
+
+// faction/reaction table replacing hardcoded "any Enemy is hostile"
+pub mod faction;
+
+// data-driven sprite/atlas visual effects (death explosions, etc.)
+pub mod effects;
+
 use bevy::prelude::*;
 use bevy_common_assets::ron::RonAssetPlugin;
+use bevy_hanabi::HanabiPlugin;
+use bevy_rapier2d::prelude::{NoUserData, RapierPhysicsPlugin};
 use config::CharactersList;
+use combat::GamePhase;
 
 pub struct CharactersPlugin;
 
 impl Plugin for CharactersPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(RonAssetPlugin::<CharactersList>::new(&["characters.ron"]))
+            .add_plugins(RonAssetPlugin::<ui::HudConfig>::new(&["hud.ron"]))
+            .add_plugins(RonAssetPlugin::<faction::ReactionTable>::new(&["reactions.ron"]))
+            .add_plugins(RonAssetPlugin::<effects::EffectsRegistry>::new(&["effects.ron"]))
+            .add_plugins(HanabiPlugin)
+            // Map tiles get static colliders and players/enemies get kinematic character
+            // controllers (see map::collision, characters::movement, characters::pathfinding).
+            .add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
             // from tutorial ch. 3
             .init_resource::<spawn::CurrentCharacterIndex>()
 
-            // added Combat
-            .init_resource::<combat::CombatState>()
-            .init_resource::<combat::GameOutcome>()
+            // Game flow is driven by GamePhase instead of ad-hoc Option/bool resources; systems
+            // are scoped to the phase(s) they apply to via run_if/OnEnter/OnExit below.
+            .init_state::<GamePhase>()
+            .add_event::<combat::DamageEvent>()
+            .add_event::<combat::DeathEvent>()
+            .init_resource::<combat::PendingCombat>()
             .init_resource::<combat::CombatLog>()
+            .init_resource::<combat::GameLog>()
+            .init_resource::<movement::InputConfig>()
             .init_resource::<npc::EnemyTracker>()
+            .init_resource::<npc::Difficulty>()
             .init_resource::<health::HealthPipTracker>()
+            .init_resource::<crate::map::spatial::SpatialIndex>()
+            .init_resource::<pathfinding::WalkGrid>()
+            .init_resource::<netcode::MatchSeed>()
+            .init_resource::<particles::ParticleConfig>()
+
+            // Rollback-eligible systems (movement, combat) advance in FixedUpdate at SIM_HZ
+            // instead of Update's variable frame rate, so two peers fed the same inputs take the
+            // exact same number of simulation steps. See netcode.rs.
+            .insert_resource(Time::<Fixed>::from_hz(netcode::SIM_HZ as f64))
+            .init_resource::<netcode::SimFrame>()
+            .init_resource::<netcode::LatestNetInput>()
+            .init_resource::<netcode::LatestSnapshot>()
 
             // from tutorial ch. 3
-            .add_systems(Startup, spawn::spawn_player)
+            .add_systems(Startup, (spawn::spawn_player, particles::setup_particle_effects, ui::load_hud_config, faction::load_reaction_table, effects::load_effects_registry))
 
             // added UI
             // Lightweight HUD systems in their own group to avoid exceeding tuple size limits
@@ -40,41 +83,116 @@ impl Plugin for CharactersPlugin {
                 ui::update_hud_health,
             ))
 
-            // added player stats, and attack animations,
+            // Systems that only make sense while exploring (movement, pickups, combat triggers)
             .add_systems(Update, (
                 // from tutorial ch. 3
                 spawn::initialize_player_character,
                 spawn::switch_character,
                 combat::sync_player_stats,
-                movement::move_player,
-                movement::update_jump_state,
-                animation::animate_characters,
-                animation::revert_attack_when_finished,
-                animation::update_animation_flags,
-                
+                combat::regen_player_mana,
+                movement::ensure_player_physics_body,
+
+                // Spatial index of NonWalkable/Water tiles backing the O(1) collision checks
+                // below; a no-op once built. See map::spatial for why this replaced per-point
+                // tile scans in spawn placement and pathfinding's WalkGrid.
+                crate::map::spatial::build_spatial_index,
+
                 // pickups
                 health::spawn_health_pips_once,
                 health::collect_health_pips,
-                
+
                 // NPCs
                 npc::spawn_enemies_once,
+                // Trickles in scaled reinforcements after the initial wave so Difficulty's
+                // time-based ramp (see npc::easy_enemy_stats) is actually observable in a run
+                // instead of only ever applying at elapsed_secs ~= 0.
+                npc::spawn_reinforcements,
+                faction::ensure_faction,
                 npc::detect_player_proximity_start_combat,
+                npc::tick_difficulty,
+
+                // Enemies path toward the player across NonWalkable/Water tiles instead of
+                // standing still until walked into. build_walk_grid is a no-op once the grid is
+                // built, so it's safe to run every frame alongside chase_player.
+                pathfinding::ensure_enemy_physics_body,
+                pathfinding::build_walk_grid,
+                pathfinding::chase_player,
+
+                // Jumping only happens while exploring, so its dust burst lives in this group;
+                // hit sparks and death dissipation (below) aren't phase-gated since damage and
+                // death transitions happen mid-Combat.
+                particles::ensure_jump_dust_tracker,
+                particles::spawn_jump_dust,
+
+                // Death-animation watchers that transition Exploring -> GameOver/Won once the
+                // triggering Death clip has finished playing out.
+                combat::handle_enemy_death_cleanup,
+                combat::handle_player_death_outcome,
+            ).run_if(in_state(GamePhase::Exploring)))
+
+            // Movement and combat turn resolution run on the fixed SIM_HZ step (see netcode.rs)
+            // rather than Update, so rollback peers fed identical inputs advance identically
+            // regardless of render frame rate.
+            .add_systems(FixedUpdate, (
+                netcode::advance_sim_frame,
+                netcode::capture_net_input,
+            ).chain())
+            .add_systems(FixedUpdate, (
+                movement::move_player,
+                movement::update_jump_state,
+            ).chain().run_if(in_state(GamePhase::Exploring)).after(netcode::capture_net_input))
+
+            // Combat-only turn resolution and its UI. The damage pipeline is chained so every
+            // DamageEvent queued this frame (by a turn resolving or a status tick) is applied and
+            // any resulting DeathEvent is handled before update_combat_ui reads the outcome.
+            .add_systems(FixedUpdate, (
                 combat::combat_input_and_turns,
-                
-                // Combat UI systems
-                combat::spawn_combat_ui_on_start,
+                combat::tick_status_effects,
+                combat::apply_damage,
+                combat::handle_death_events,
                 combat::update_combat_ui,
-                combat::cleanup_combat_ui_on_end,
-                // Outcome overlays and restart
-                combat::show_outcome_overlay,
+            ).chain().run_if(in_state(GamePhase::Combat)).after(netcode::capture_net_input))
+
+            // Snapshot whatever the fixed step above just settled on, last, so a future rollback
+            // resimulation has a consistent confirmed-frame state to restore from.
+            .add_systems(FixedUpdate, netcode::capture_snapshot
+                .after(movement::update_jump_state)
+                .after(combat::update_combat_ui))
+
+            // State-scoped spawn/despawn of combat UI and the ActiveCombat resource
+            .add_systems(OnEnter(GamePhase::Combat), (combat::start_combat, combat::spawn_combat_ui, combat::force_player_idle))
+            .add_systems(OnExit(GamePhase::Combat), (combat::end_combat, combat::cleanup_combat_ui))
+
+            // State-scoped outcome overlays
+            .add_systems(OnEnter(GamePhase::GameOver), (combat::spawn_game_over_overlay, combat::force_player_idle))
+            .add_systems(OnExit(GamePhase::GameOver), combat::despawn_outcome_overlay)
+            .add_systems(OnEnter(GamePhase::Won), (combat::spawn_won_overlay, combat::force_player_idle))
+            .add_systems(OnExit(GamePhase::Won), combat::despawn_outcome_overlay)
+
+            // Restart and quit keybinds work in any phase
+            .add_systems(Update, (
                 combat::handle_restart_input,
-                // Quit keybind
                 combat::handle_quit_input,
             ))
-            // Additional systems kept in separate tuple to avoid exceeding tuple size limits
+
+            // Hit sparks and death dissipation aren't phase-gated: DamageEvents and the
+            // transition into AnimationType::Death both happen while still in GamePhase::Combat.
             .add_systems(Update, (
-                combat::handle_enemy_death_cleanup,
-                combat::handle_player_death_outcome,
+                particles::ensure_death_fx_tracker,
+                particles::spawn_hit_sparks,
+                particles::spawn_death_dissipation,
+                effects::tick_effects,
+            ))
+
+            // Animation drivers are ungated rather than Exploring-only: they own the only ticking
+            // of AnimationTimer/atlas index, and combat_input_and_turns sets AnimationType::Attack
+            // and then gates the next turn on that clip completing (see combat.rs). Confining
+            // these to Exploring would freeze attack animations mid-Combat and wedge turn
+            // advancement.
+            .add_systems(Update, (
+                animation::animate_characters,
+                animation::revert_attack_when_finished,
+                animation::update_animation_flags,
             ));
     }
 }
\ No newline at end of file
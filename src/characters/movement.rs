@@ -1,16 +1,51 @@
 // Movement System
 // from https://aibodh.com/posts/bevy-rust-game-development-chapter-3/
 // added collision handling, idle when combat starts, and player death handling
+//
+// Movement is now driven by bevy_rapier2d's kinematic character controller instead of a manual
+// swept sub-step loop against NonWalkable/Water AABBs: map tiles get static colliders at
+// generation time (see map::collision), the controller does the sweeping, and we just hand it a
+// desired per-frame translation.
 
 use bevy::prelude::*;
-use crate::map::collision::{NonWalkable, Water, nonwalkable_half_extent, water_half_extent};
+use bevy_rapier2d::prelude::*;
 use crate::map::generate::TILE_SIZE;
 use crate::characters::animation::*;
-use crate::characters::combat::{CombatState, GameOutcome};
 use crate::characters::config::{CharacterEntry, AnimationType};
 
-/// Read directional input and return a direction vector
-fn read_movement_input(input: &ButtonInput<KeyCode>) -> Vec2 {
+/// Half-extent of the player's capsule-ish collider, smaller than a full tile so the player can
+/// stand flush against solid tiles without visually overlapping them.
+const PLAYER_COLLIDER_HALF_EXTENT: f32 = TILE_SIZE * 0.35;
+
+// Which gamepad buttons drive jump and the in-combat "confirm" action. Exposed as a resource
+// (rather than hardcoded GamepadButton::South/East) so these can be rebound without touching
+// movement.rs or combat.rs.
+#[derive(Resource, Debug, Clone)]
+pub struct InputConfig {
+    pub jump_button: GamepadButton,
+    pub combat_button: GamepadButton,
+}
+
+impl Default for InputConfig {
+    fn default() -> Self {
+        Self { jump_button: GamepadButton::South, combat_button: GamepadButton::East }
+    }
+}
+
+// This is a single-player scene, so only the first connected gamepad drives the player.
+fn first_gamepad(gamepads: &Query<&Gamepad>) -> Option<&Gamepad> {
+    gamepads.iter().next()
+}
+
+/// True if the configured gamepad button for `jump`/`combat` was just pressed this frame.
+pub fn gamepad_just_pressed(gamepads: &Query<&Gamepad>, button: GamepadButton) -> bool {
+    first_gamepad(gamepads).is_some_and(|gamepad| gamepad.just_pressed(button))
+}
+
+/// Read directional input and return a direction vector. Keyboard keys each contribute a unit
+/// vector as before; the left stick contributes its raw (magnitude-preserving) reading so
+/// `calculate_movement_speed`'s caller can scale speed by how far the stick is pushed.
+fn read_movement_input(input: &ButtonInput<KeyCode>, gamepads: &Query<&Gamepad>) -> Vec2 {
     const MOVEMENT_KEYS: [(KeyCode, Vec2); 8] = [
         /* Arrow keys controls */
         (KeyCode::ArrowLeft, Vec2::NEG_X),
@@ -25,10 +60,17 @@ fn read_movement_input(input: &ButtonInput<KeyCode>) -> Vec2 {
         (KeyCode::KeyD, Vec2::X),
     ];
 
-    MOVEMENT_KEYS.iter()
+    let keyboard_dir: Vec2 = MOVEMENT_KEYS.iter()
         .filter(|(key, _)| input.pressed(*key))
         .map(|(_, dir)| *dir)
-        .sum()
+        .sum();
+
+    // Read the stick's current value every frame instead of tracking held-vs-released axis
+    // events: a released stick reports an exact 0.0 axis, so reading it fresh each frame already
+    // zeroes movement cleanly without any extra "was this released" bookkeeping.
+    let stick_dir = first_gamepad(gamepads).map(|gamepad| gamepad.left_stick()).unwrap_or(Vec2::ZERO);
+
+    keyboard_dir + stick_dir
 }
 
 /// Calculate movement speed based on character config and running state
@@ -45,46 +87,63 @@ fn calculate_movement_speed(character: &CharacterEntry, is_running: bool) -> f32
 #[derive(Component)]
 pub struct Player;
 
-/// Handle player movement input and update transform/animation
+/// Give the player a kinematic physics body the first time it's seen without one. spawn.rs
+/// predates the rapier integration, so this backfills the body/collider/controller instead of
+/// threading them through the player spawn bundle.
+pub fn ensure_player_physics_body(
+    mut commands: Commands,
+    players: Query<(Entity, Option<&CharacterEntry>), (With<Player>, Without<KinematicCharacterController>)>,
+) {
+    for (entity, character) in players.iter() {
+        // Scale the collider by the character's tile footprint (see map::spatial::TileSize) so a
+        // multi-tile player doesn't get a 1x1 collider; CharacterEntry isn't attached yet on the
+        // very first frame, so the body is only inserted once it is.
+        let Some(character) = character else { continue; };
+        let footprint = character.footprint;
+        let half = Vec2::new(footprint.width as f32, footprint.height as f32) * PLAYER_COLLIDER_HALF_EXTENT;
+        commands.entity(entity).insert((
+            RigidBody::KinematicPositionBased,
+            Collider::cuboid(half.x, half.y),
+            KinematicCharacterController::default(),
+        ));
+    }
+}
+
+/// Handle player movement input and update transform/animation.
+/// Only scheduled with run_if(in_state(GamePhase::Exploring)), so combat/outcome idling is
+/// handled by combat::force_player_idle on entering those states instead of guarding here.
+/// Runs in `FixedUpdate` at `netcode::SIM_DT` rather than `Time::delta_secs()`: rollback requires
+/// both peers to advance position by the exact same step size every tick, which a frame-rate-
+/// dependent delta can't guarantee.
 pub fn move_player(
     input: Res<ButtonInput<KeyCode>>,
-    time: Res<Time>,
-    combat: Res<CombatState>,
-    outcome: Res<GameOutcome>,
+    gamepads: Query<&Gamepad>,
+    input_config: Res<InputConfig>,
     mut query: Query<(
-        &mut Transform,
+        &mut KinematicCharacterController,
         &mut AnimationController,
         &mut AnimationState,
         &CharacterEntry,
     ), With<Player>>,
-    blocking_tiles: Query<&GlobalTransform, With<NonWalkable>>,
-    water_tiles: Query<&GlobalTransform, With<Water>>,
 ) {
-    let Ok((mut transform, mut animated, mut state, character)) = query.single_mut() else {
+    let Ok((mut controller, mut animated, mut state, character)) = query.single_mut() else {
         return;
     };
 
     // If currently playing a Death animation, disable movement entirely
     if matches!(animated.current_animation, AnimationType::Death) {
         state.is_moving = false;
+        controller.translation = None;
         return;
     }
 
-    // When combat starts (or an outcome overlay is showing), force player to idle
-    // and disable further movement processing.
-    if combat.active.is_some() || !matches!(*outcome, GameOutcome::None) {
-        state.is_moving = false;
-        // Do not override Attack/Death animations while they are playing
-        if !state.is_jumping && !matches!(animated.current_animation, AnimationType::Death | AnimationType::Attack) {
-            animated.current_animation = AnimationType::Walk; // use Walk's idle frame as idle
-        }
-        return;
-    }
+    let direction = read_movement_input(&input, &gamepads);
+    // Clamp combined keyboard+stick magnitude to 1 (keyboard alone already behaves this way once
+    // normalized below) so a half-pushed stick moves at half speed instead of full speed.
+    let magnitude = direction.length().min(1.0);
 
-    let direction = read_movement_input(&input);
-
-    // Check for jump input (space key)
-    if input.just_pressed(KeyCode::Space) {
+    // Check for jump input (space key or the configured gamepad button)
+    if input.just_pressed(KeyCode::Space) || gamepad_just_pressed(&gamepads, input_config.jump_button) {
         state.is_jumping = true;
         animated.current_animation = AnimationType::Jump;
     }
@@ -94,41 +153,12 @@ pub fn move_player(
 
     // Handle movement
     if direction != Vec2::ZERO {
-        let move_speed = calculate_movement_speed(character, is_running);
-        let delta = direction.normalize() * move_speed * time.delta_secs();
-
-        // Collision-aware movement with swept sub-steps to avoid tunneling
-        let mut new_pos = transform.translation;
-
-        // Determine number of sub-steps based on the maximum component
-        // Keep each step relatively small vs tile size to avoid skipping over thin obstacles
-        let max_component = delta.x.abs().max(delta.y.abs());
-        let max_step_len = TILE_SIZE * 0.20; // at most 20% of a tile per sub-step
-        let steps = if max_component > 0.0 {
-            (max_component / max_step_len).ceil().clamp(1.0, 8.0) as u32 // clamp to avoid perf issues
-        } else { 1 };
-
-        let step = Vec2::new(delta.x / steps as f32, delta.y / steps as f32);
-
-        for _ in 0..steps {
-            // Attempt X movement for this sub-step
-            if step.x != 0.0 {
-                let candidate = Vec2::new(new_pos.x + step.x, new_pos.y);
-                if !would_collide_point(candidate, &blocking_tiles, &water_tiles) {
-                    new_pos.x += step.x;
-                }
-            }
-
-            // Attempt Y movement for this sub-step
-            if step.y != 0.0 {
-                let candidate = Vec2::new(new_pos.x, new_pos.y + step.y);
-                if !would_collide_point(candidate, &blocking_tiles, &water_tiles) {
-                    new_pos.y += step.y;
-                }
-            }
-        }
+        let move_speed = calculate_movement_speed(character, is_running) * magnitude;
 
-        transform.translation = new_pos;
+        // Rapier's kinematic character controller sweeps this against the Collider/RigidBody
+        // bodies map tiles get in map::collision, so tunneling is handled without a manual
+        // sub-step loop or clamp.
+        controller.translation = Some(direction.normalize() * move_speed * crate::characters::netcode::SIM_DT);
 
         animated.facing = Facing::from_direction(direction);
 
@@ -141,9 +171,12 @@ pub fn move_player(
                 AnimationType::Walk
             };
         }
-    } else if !state.is_jumping {
-        state.is_moving = false;
-        animated.current_animation = AnimationType::Walk;
+    } else {
+        controller.translation = None;
+        if !state.is_jumping {
+            state.is_moving = false;
+            animated.current_animation = AnimationType::Walk;
+        }
     }
 }
 
@@ -180,33 +213,3 @@ pub fn update_jump_state(
     }
 }
 
-/// Check if a point would overlap any solid (NonWalkable) or Water tile's AABB
-fn would_collide_point(
-    point: Vec2,
-    solids: &Query<&GlobalTransform, With<NonWalkable>>,
-    waters: &Query<&GlobalTransform, With<Water>>,
-) -> bool {
-    // Solids: full half extent, inclusive test
-    let solid_half = nonwalkable_half_extent();
-    for gt in solids.iter() {
-        let pos = gt.translation().truncate();
-        let dx = (point.x - pos.x).abs();
-        let dy = (point.y - pos.y).abs();
-        if dx <= solid_half && dy <= solid_half {
-            return true;
-        }
-    }
-
-    // Water: slightly smaller half extent and strict inequality so edges on grass aren't blocked
-    let water_half = water_half_extent();
-    for gt in waters.iter() {
-        let pos = gt.translation().truncate();
-        let dx = (point.x - pos.x).abs();
-        let dy = (point.y - pos.y).abs();
-        if dx < water_half && dy < water_half {
-            return true;
-        }
-    }
-
-    false
-}
@@ -0,0 +1,206 @@
+// Deterministic-simulation groundwork for an eventual GGRS-style rollback co-op mode.
+//
+// Scope note: this tree has no networking crate and no Cargo.toml to add one to, so there is no
+// UDP transport, prediction, or resimulation loop here yet — that needs bevy_ggrs (or similar)
+// wired up once this project has a real dependency manifest. What *can* land without that
+// dependency, and what rollback will need regardless of transport, is: a fixed simulation
+// timestep, a Pod-sized per-frame input packing, and a snapshot/restore path for the component
+// data that affects simulation. Those three pieces live here and are scheduled in FixedUpdate by
+// characters::mod (advance_sim_frame, capture_net_input, capture_snapshot) so they're exercised
+// every tick instead of sitting as unused scaffolding.
+
+use bevy::prelude::*;
+
+use crate::characters::combat::{CombatStats, GamePhase};
+use crate::characters::movement::Player;
+use crate::characters::npc::Enemy;
+
+/// Simulation rate both peers must agree on; rollback requires identical input sequences to
+/// advance the exact same number of steps, which means every rollback-eligible system needs a
+/// fixed `dt` instead of `Time::delta_secs()`.
+pub const SIM_HZ: f32 = 60.0;
+pub const SIM_DT: f32 = 1.0 / SIM_HZ;
+
+/// Monotonic count of fixed simulation steps taken. This is the "frame counter" rollback indexes
+/// snapshots and input by; `Time::elapsed` isn't usable for that since it's wall-clock, not
+/// step-count. Advanced once per `FixedUpdate` pass by `advance_sim_frame`, which must run before
+/// any other rollback-eligible system in that schedule.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct SimFrame(pub u64);
+
+pub fn advance_sim_frame(mut frame: ResMut<SimFrame>) {
+    frame.0 += 1;
+}
+
+/// Seed both peers agree on (out-of-band, e.g. during lobby handshake) before a match starts.
+/// Every system that spawns or places things during simulation must derive its randomness from
+/// this instead of OS entropy, or two peers fed identical inputs would diverge anyway. There's no
+/// lobby/handshake in this tree yet, so this currently just holds a placeholder value set at
+/// startup; wiring it up to a real exchange is part of the transport layer this module doesn't
+/// implement.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct MatchSeed(pub u64);
+
+impl Default for MatchSeed {
+    fn default() -> Self {
+        Self(0)
+    }
+}
+
+/// One frame of a player's input, packed into a single byte so it's cheap to send over UDP and
+/// satisfies the `Pod`-style bound GGRS expects of its input type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NetInput(pub u8);
+
+impl NetInput {
+    const UP: u8 = 1 << 0;
+    const DOWN: u8 = 1 << 1;
+    const LEFT: u8 = 1 << 2;
+    const RIGHT: u8 = 1 << 3;
+    const JUMP: u8 = 1 << 4;
+    const RUN: u8 = 1 << 5;
+    const CONFIRM: u8 = 1 << 6;
+
+    /// Pack this frame's keyboard state. Only ever reads `ButtonInput`, never wall-clock time, so
+    /// two peers fed the same key events produce bit-identical `NetInput`s.
+    pub fn from_keyboard(input: &ButtonInput<KeyCode>) -> Self {
+        let mut bits = 0u8;
+        if input.pressed(KeyCode::ArrowUp) || input.pressed(KeyCode::KeyW) {
+            bits |= Self::UP;
+        }
+        if input.pressed(KeyCode::ArrowDown) || input.pressed(KeyCode::KeyS) {
+            bits |= Self::DOWN;
+        }
+        if input.pressed(KeyCode::ArrowLeft) || input.pressed(KeyCode::KeyA) {
+            bits |= Self::LEFT;
+        }
+        if input.pressed(KeyCode::ArrowRight) || input.pressed(KeyCode::KeyD) {
+            bits |= Self::RIGHT;
+        }
+        if input.just_pressed(KeyCode::Space) {
+            bits |= Self::JUMP;
+        }
+        if input.pressed(KeyCode::ShiftLeft) || input.pressed(KeyCode::ShiftRight) {
+            bits |= Self::RUN;
+        }
+        if input.just_pressed(KeyCode::Enter) {
+            bits |= Self::CONFIRM;
+        }
+        Self(bits)
+    }
+
+    pub fn direction(&self) -> Vec2 {
+        let mut dir = Vec2::ZERO;
+        if self.0 & Self::UP != 0 {
+            dir.y += 1.0;
+        }
+        if self.0 & Self::DOWN != 0 {
+            dir.y -= 1.0;
+        }
+        if self.0 & Self::LEFT != 0 {
+            dir.x -= 1.0;
+        }
+        if self.0 & Self::RIGHT != 0 {
+            dir.x += 1.0;
+        }
+        dir
+    }
+
+    pub fn jump(&self) -> bool {
+        self.0 & Self::JUMP != 0
+    }
+    pub fn run(&self) -> bool {
+        self.0 & Self::RUN != 0
+    }
+    pub fn confirm(&self) -> bool {
+        self.0 & Self::CONFIRM != 0
+    }
+}
+
+/// This frame's packed `NetInput`, captured once per fixed step. Rollback's actual job -
+/// buffering a short history per peer and replaying it across a resimulation - needs the
+/// transport layer this module doesn't implement; until then this just proves the packing step
+/// itself runs every tick instead of sitting unused.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct LatestNetInput(pub NetInput);
+
+/// Pack this tick's keyboard state into `LatestNetInput`. Keyed off `ButtonInput` only, same as
+/// `NetInput::from_keyboard`, so it stays reproducible from recorded input alone.
+pub fn capture_net_input(input: Res<ButtonInput<KeyCode>>, mut latest: ResMut<LatestNetInput>) {
+    latest.0 = NetInput::from_keyboard(&input);
+}
+
+/// The per-entity slice of simulation state a rollback restores: each player's and enemy's
+/// `Transform` and `CombatStats`. Other state that `move_player`, `update_jump_state`, and combat
+/// turn resolution read or write (animation state, abilities, progression, status effects) is not
+/// captured. Presentation-only data (sprite/atlas indices, HUD text) is excluded so rendering
+/// keeps reading interpolated state instead of snapping on every resimulated frame.
+#[derive(Clone, Copy, Debug)]
+struct EntitySnapshot {
+    entity: Entity,
+    transform: Transform,
+    stats: Option<CombatStats>,
+}
+
+/// A full confirmed-frame snapshot: every rollback-relevant entity plus the global `GamePhase`,
+/// which is what `CombatState` would have captured before chunk1-1 folded it into a real
+/// `States` type.
+#[derive(Clone, Debug, Default)]
+pub struct SimSnapshot {
+    frame: u64,
+    phase: Option<GamePhase>,
+    entities: Vec<EntitySnapshot>,
+}
+
+/// Capture the current rollback-relevant world state for fixed-step `frame`. A plain function
+/// rather than a system: `frame: u64` isn't a valid `SystemParam`, and the frame a snapshot
+/// belongs to has to be supplied by the caller (here, `SimFrame`) rather than inferred.
+pub fn serialize_world(
+    frame: u64,
+    phase: Option<Res<State<GamePhase>>>,
+    players: Query<(Entity, &Transform, Option<&CombatStats>), With<Player>>,
+    enemies: Query<(Entity, &Transform, Option<&CombatStats>), With<Enemy>>,
+) -> SimSnapshot {
+    let entities = players
+        .iter()
+        .chain(enemies.iter())
+        .map(|(entity, transform, stats)| EntitySnapshot { entity, transform: *transform, stats: stats.copied() })
+        .collect();
+
+    SimSnapshot { frame, phase: phase.map(|s| *s.get()), entities }
+}
+
+/// Most recent confirmed-frame snapshot, what a prediction miss would roll back to once rollback
+/// actually resimulates. `None` until the first `capture_snapshot` tick.
+#[derive(Resource, Debug, Default)]
+pub struct LatestSnapshot(pub Option<SimSnapshot>);
+
+/// Run `serialize_world` every fixed step and bank the result, so the snapshot path is actually
+/// exercised instead of sitting dead. Must run after every other rollback-eligible system in the
+/// same `FixedUpdate` pass so it captures post-simulation state for that frame.
+pub fn capture_snapshot(
+    frame: Res<SimFrame>,
+    phase: Option<Res<State<GamePhase>>>,
+    players: Query<(Entity, &Transform, Option<&CombatStats>), With<Player>>,
+    enemies: Query<(Entity, &Transform, Option<&CombatStats>), With<Enemy>>,
+    mut latest: ResMut<LatestSnapshot>,
+) {
+    latest.0 = Some(serialize_world(frame.0, phase, players, enemies));
+}
+
+/// Restore a previously captured snapshot, e.g. after a prediction miss. `commands` is used
+/// rather than a `Query` because the entities being restored aren't necessarily the ones any one
+/// caller already holds a query for.
+pub fn deserialize_world(commands: &mut Commands, snapshot: &SimSnapshot) {
+    for saved in &snapshot.entities {
+        let mut ec = commands.entity(saved.entity);
+        ec.insert(saved.transform);
+        if let Some(stats) = saved.stats {
+            ec.insert(stats);
+        }
+    }
+    // Restoring `GamePhase` itself is left to the caller: it's a `States` transition, not a plain
+    // component insert, and must go through `NextState` so `OnEnter`/`OnExit` systems stay
+    // consistent with whatever phase is rolled back to.
+    let _ = snapshot.phase;
+}
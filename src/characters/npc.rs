@@ -3,45 +3,175 @@
 use bevy::prelude::*;
 
 use crate::characters::animation::*;
-use crate::characters::combat::{ActiveCombat, CombatState, CombatStats, GameOutcome};
-use crate::characters::config::{CharacterEntry, CharactersList};
+use crate::characters::combat::{ActiveCombatInfo, CombatStats, DamageType, GamePhase, PendingCombat};
+use crate::characters::config::{AnimationType, CharacterEntry, CharactersList};
+use crate::characters::faction::{Faction, Reaction, ReactionTable, ReactionTableHandle};
 use crate::characters::movement::Player;
+use crate::characters::netcode::MatchSeed;
+use crate::characters::pathfinding::EnemyPath;
 use crate::characters::spawn::CharactersListResource;
 
 use crate::map::generate::{map_pixel_dimensions, TILE_SIZE};
-use crate::map::collision::{NonWalkable, Water, nonwalkable_half_extent, water_half_extent};
+use crate::map::spatial::SpatialIndex;
 
-const ENEMY_SCALE: f32 = 0.8;
+pub const ENEMY_SCALE: f32 = 0.8;
 const ENEMY_Z: f32 = 15.0;
 const ENEMIES_TO_SPAWN: usize = 3;
 
+// How often (and up to how many) scaled reinforcements trickle in after the initial wave, so the
+// Difficulty ramp (see easy_enemy_stats) is actually observable in a long run instead of only ever
+// applying to the wave spawned at elapsed_secs ~= 0. See spawn_reinforcements.
+const REINFORCEMENT_INTERVAL_SECS: f32 = 45.0;
+const MAX_ALIVE_ENEMIES: usize = 8;
+
+/// How far (in tiles) an enemy without an explicit override can see the player, subject to
+/// line-of-sight blocking by NonWalkable tiles. See detect_player_proximity_start_combat.
+const DEFAULT_SIGHT_RANGE_TILES: f32 = 5.0;
+
 // Public marker for enemy entities
 #[derive(Component)]
-pub struct Enemy;
+pub struct Enemy {
+    pub sight_range: f32,
+}
+
+impl Default for Enemy {
+    fn default() -> Self {
+        Self { sight_range: DEFAULT_SIGHT_RANGE_TILES }
+    }
+}
 
 // Tracks enemy spawn state and remaining count
 #[derive(Resource, Default)]
 pub struct EnemyTracker {
     pub spawned: bool,
     pub alive: usize,
+    // Total enemies ever spawned this run, used to seed each reinforcement's placement RNG
+    // deterministically (seed.0 + total_spawned) instead of reusing the initial wave's seed.
+    total_spawned: u64,
+    // Counts down to the next scaled reinforcement; see spawn_reinforcements.
+    reinforcement_timer: f32,
+}
+
+impl EnemyTracker {
+    // Full reset for a restarted run: forgets everything spawned so far so the initial wave and
+    // reinforcement trickle both start over from scratch.
+    pub fn reset_for_restart(&mut self) {
+        self.spawned = false;
+        self.alive = 0;
+        self.total_spawned = 0;
+        self.reinforcement_timer = 0.0;
+    }
+}
+
+// Escalating-pressure timer: the longer the current run lasts, the harder enemies hit and the
+// more HP they have. `ramp_rate` and `ceiling` are exposed so the curve can be tuned.
+#[derive(Resource)]
+pub struct Difficulty {
+    pub elapsed_secs: f32,
+    pub ramp_rate: f32,
+    pub ceiling: f32,
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Self { elapsed_secs: 0.0, ramp_rate: 0.15, ceiling: 3.0 }
+    }
 }
 
-fn easy_enemy_stats(entry: &CharacterEntry) -> CombatStats {
-    // Build enemy stats directly from the RON entry.
-    // Health and attack come from the config
+impl Difficulty {
+    /// 1.0 at the start of a run, growing linearly with elapsed minutes and clamped at `ceiling`.
+    pub fn scale(&self) -> f32 {
+        (1.0 + (self.elapsed_secs / 60.0) * self.ramp_rate).min(self.ceiling)
+    }
+}
+
+// Accumulate run time while exploring; restarts reset this via handle_restart_input.
+pub fn tick_difficulty(time: Res<Time>, mut difficulty: ResMut<Difficulty>) {
+    difficulty.elapsed_secs += time.delta_secs();
+}
+
+fn easy_enemy_stats(entry: &CharacterEntry, difficulty: &Difficulty) -> CombatStats {
+    // Build enemy stats directly from the RON entry, then apply the difficulty ramp so waves
+    // encountered later in a run hit harder and tank more.
     // keep low defense to make fights readable.
-    let max_hp_i = entry.max_health.max(1.0).round() as i32;
+    let scale = difficulty.scale();
+    let max_hp_i = (entry.max_health.max(1.0) * scale).round() as i32;
+    let attack_i = (entry.attack_damage.max(1.0) * scale).round() as i32;
     CombatStats {
         max_hp: max_hp_i,
         hp: max_hp_i,
-        attack: entry.attack_damage.max(1.0).round() as i32,
+        attack: attack_i,
         defense: 0,
-        // Keep simple defaults for crit/evade
-        crit_chance: 0.10,
+        // Crit chance ramps too, but capped so it never becomes a guaranteed crit.
+        crit_chance: (0.10 * scale).min(0.6),
         evade_chance: 0.10,
+        damage_type: entry.damage_type.unwrap_or(DamageType::Physical),
     }
 }
 
+// Spawns one enemy of a random configured type at a sampled valid position, scaled by the current
+// Difficulty. Shared by the initial wave (spawn_enemies_once) and trickle-in reinforcements
+// (spawn_reinforcements) so both stay scaled by whatever the ramp is at the moment they spawn.
+fn spawn_one_enemy(
+    commands: &mut Commands,
+    list: &CharactersList,
+    atlas_layouts: &mut Assets<TextureAtlasLayout>,
+    asset_server: &AssetServer,
+    difficulty: &Difficulty,
+    spatial_index: &SpatialIndex,
+    half: Vec2,
+    rng: &mut rand::rngs::StdRng,
+) {
+    use rand::Rng;
+
+    let idx = if list.characters.len() > 1 {
+        rng.random_range(0..list.characters.len())
+    } else { 0 };
+    let enemy_entry: &CharacterEntry = &list.characters[idx];
+
+    // Prepare atlas and texture for this enemy type
+    let layout = {
+        let max_row = enemy_entry.calculate_max_animation_row();
+        atlas_layouts.add(TextureAtlasLayout::from_grid(
+            UVec2::splat(enemy_entry.tile_size),
+            enemy_entry.atlas_columns as u32,
+            (max_row + 1) as u32,
+            None,
+            None,
+        ))
+    };
+    let texture: Handle<Image> = asset_server.load(&enemy_entry.texture_path);
+
+    // sample a valid ground position (not on water/non-walkable), checking the whole footprint
+    // rather than just its center so multi-tile enemies don't spawn half-embedded in a wall.
+    let mut pos = Vec2::ZERO;
+    for _attempt in 0..200 {
+        let x = rng.random_range((-half.x + TILE_SIZE)..(half.x - TILE_SIZE));
+        let y = rng.random_range((-half.y + TILE_SIZE)..(half.y - TILE_SIZE));
+        let candidate = Vec2::new(x, y);
+        if !spatial_index.is_blocked_footprint(candidate, enemy_entry.footprint) {
+            pos = candidate;
+            break;
+        }
+    }
+    let sprite = Sprite::from_atlas_image(
+        texture.clone(),
+        TextureAtlas { layout: layout.clone(), index: 0 },
+    );
+
+    commands.spawn((
+        Enemy::default(),
+        easy_enemy_stats(enemy_entry, difficulty),
+        enemy_entry.clone(),
+        EnemyPath::default(),
+        AnimationController::default(),
+        AnimationState::default(),
+        AnimationTimer(Timer::from_seconds(DEFAULT_ANIMATION_FRAME_TIME, TimerMode::Repeating)),
+        Transform::from_translation(Vec3::new(pos.x, pos.y, ENEMY_Z)).with_scale(Vec3::splat(ENEMY_SCALE)),
+        sprite,
+    ));
+}
+
 pub fn spawn_enemies_once(
     mut commands: Commands,
     characters_lists: Res<Assets<CharactersList>>,
@@ -49,109 +179,123 @@ pub fn spawn_enemies_once(
     mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     asset_server: Res<AssetServer>,
     mut tracker: ResMut<EnemyTracker>,
-    blocking_tiles: Query<&GlobalTransform, With<NonWalkable>>,
-    water_tiles: Query<&GlobalTransform, With<Water>>,
+    difficulty: Res<Difficulty>,
+    seed: Res<MatchSeed>,
+    spatial_index: Res<SpatialIndex>,
 ) {
     if tracker.spawned { return; }
     let Some(list_res) = characters_list_res else { return; };
     let Some(list) = characters_lists.get(&list_res.handle) else { return; };
     if list.characters.is_empty() { return; }
+    // Wait for the spatial index so placement sampling below doesn't treat the whole map as
+    // walkable before terrain has actually spawned.
+    if !spatial_index.is_built() { return; }
 
     let map_size = map_pixel_dimensions();
     let half = map_size * 0.5;
 
-    // rng using rand crate
-    use rand::Rng;
-    let mut rng = rand::rng();
-
-    // helper to test collision with solid or water tiles
-    let would_collide = |point: Vec2| -> bool {
-        let solid_half = nonwalkable_half_extent();
-        for gt in blocking_tiles.iter() {
-            let pos = gt.translation().truncate();
-            let dx = (point.x - pos.x).abs();
-            let dy = (point.y - pos.y).abs();
-            if dx <= solid_half && dy <= solid_half { return true; }
-        }
-        let water_half = water_half_extent();
-        for gt in water_tiles.iter() {
-            let pos = gt.translation().truncate();
-            let dx = (point.x - pos.x).abs();
-            let dy = (point.y - pos.y).abs();
-            if dx < water_half && dy < water_half { return true; }
-        }
-        false
-    };
+    // Seeded from MatchSeed rather than OS entropy, so two rollback peers who agree on a seed
+    // (see netcode::MatchSeed) spawn identical enemies in identical places.
+    use rand::SeedableRng;
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed.0);
 
     for _ in 0..ENEMIES_TO_SPAWN {
-        // Pick a random character entry for variety
-        let idx = if list.characters.len() > 1 {
-            rng.random_range(0..list.characters.len())
-        } else { 0 };
-        let enemy_entry: &CharacterEntry = &list.characters[idx];
-
-        // Prepare atlas and texture for this enemy type
-        let layout = {
-            let max_row = enemy_entry.calculate_max_animation_row();
-            atlas_layouts.add(TextureAtlasLayout::from_grid(
-                UVec2::splat(enemy_entry.tile_size),
-                enemy_entry.atlas_columns as u32,
-                (max_row + 1) as u32,
-                None,
-                None,
-            ))
-        };
-        let texture: Handle<Image> = asset_server.load(&enemy_entry.texture_path);
-
-        // sample a valid ground position (not on water/non-walkable)
-        let mut pos = Vec2::ZERO;
-        for _attempt in 0..200 {
-            let x = rng.random_range((-half.x + TILE_SIZE)..(half.x - TILE_SIZE));
-            let y = rng.random_range((-half.y + TILE_SIZE)..(half.y - TILE_SIZE));
-            let candidate = Vec2::new(x, y);
-            if !would_collide(candidate) {
-                pos = candidate;
-                break;
-            }
-        }
-        let sprite = Sprite::from_atlas_image(
-            texture.clone(),
-            TextureAtlas { layout: layout.clone(), index: 0 },
-        );
-
-        commands.spawn((
-            Enemy,
-            easy_enemy_stats(enemy_entry),
-            enemy_entry.clone(),
-            AnimationController::default(),
-            AnimationState::default(),
-            AnimationTimer(Timer::from_seconds(DEFAULT_ANIMATION_FRAME_TIME, TimerMode::Repeating)),
-            Transform::from_translation(Vec3::new(pos.x, pos.y, ENEMY_Z)).with_scale(Vec3::splat(ENEMY_SCALE)),
-            sprite,
-        ));
+        spawn_one_enemy(&mut commands, list, &mut atlas_layouts, &asset_server, &difficulty, &spatial_index, half, &mut rng);
     }
     tracker.alive = ENEMIES_TO_SPAWN;
+    tracker.total_spawned = ENEMIES_TO_SPAWN as u64;
+    tracker.reinforcement_timer = REINFORCEMENT_INTERVAL_SECS;
     tracker.spawned = true;
 }
 
-// Start combat when the player is close to an enemy
+// Trickles in one scaled reinforcement every REINFORCEMENT_INTERVAL_SECS (up to
+// MAX_ALIVE_ENEMIES alive at once) so Difficulty's time-based ramp is observable within a single
+// run instead of only ever applying to the wave spawned at elapsed_secs ~= 0.
+pub fn spawn_reinforcements(
+    mut commands: Commands,
+    characters_lists: Res<Assets<CharactersList>>,
+    characters_list_res: Option<Res<CharactersListResource>>,
+    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    asset_server: Res<AssetServer>,
+    mut tracker: ResMut<EnemyTracker>,
+    difficulty: Res<Difficulty>,
+    time: Res<Time>,
+    seed: Res<MatchSeed>,
+    spatial_index: Res<SpatialIndex>,
+) {
+    if !tracker.spawned || !spatial_index.is_built() { return; }
+    if tracker.alive >= MAX_ALIVE_ENEMIES { return; }
+    let Some(list_res) = characters_list_res else { return; };
+    let Some(list) = characters_lists.get(&list_res.handle) else { return; };
+    if list.characters.is_empty() { return; }
+
+    tracker.reinforcement_timer -= time.delta_secs();
+    if tracker.reinforcement_timer > 0.0 { return; }
+    tracker.reinforcement_timer = REINFORCEMENT_INTERVAL_SECS;
+
+    let map_size = map_pixel_dimensions();
+    let half = map_size * 0.5;
+
+    // Each reinforcement gets its own deterministic seed derived from how many enemies this run
+    // has spawned so far, rather than reusing the initial wave's seed and rng state.
+    use rand::SeedableRng;
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed.0.wrapping_add(tracker.total_spawned));
+    spawn_one_enemy(&mut commands, list, &mut atlas_layouts, &asset_server, &difficulty, &spatial_index, half, &mut rng);
+    tracker.alive += 1;
+    tracker.total_spawned += 1;
+}
+
+// Start combat when the player is within an enemy's sight range and visible to it, instead of
+// raw Euclidean distance, *and* the two factions' reaction is Hostile — an enemy on the far side
+// of a tree no longer "sees" the player through it, and neutral wildlife no longer forces a fight
+// just by being nearby. Scheduled with run_if(in_state(GamePhase::Exploring)), so there's no need
+// to check whether a fight is already in progress here; we just hand the pair off and request the
+// transition.
 pub fn detect_player_proximity_start_combat(
-    mut state: ResMut<CombatState>,
-    outcome: Res<GameOutcome>,
-    player_q: Query<(Entity, &GlobalTransform), With<Player>>,
-    enemies_q: Query<(Entity, &GlobalTransform), With<Enemy>>,
+    mut pending: ResMut<PendingCombat>,
+    mut next_phase: ResMut<NextState<GamePhase>>,
+    spatial_index: Res<SpatialIndex>,
+    reaction_handle: Option<Res<ReactionTableHandle>>,
+    reaction_tables: Res<Assets<ReactionTable>>,
+    player_q: Query<(Entity, &GlobalTransform, Option<&Faction>), With<Player>>,
+    enemies_q: Query<(Entity, &GlobalTransform, &Enemy, Option<&Faction>, &CombatStats, &AnimationController)>,
 ) {
-    if state.active.is_some() { return; }
-    if !matches!(*outcome, GameOutcome::None) { return; }
-    let Ok((player_e, p_tf)) = player_q.single() else { return; };
+    let Ok((player_e, p_tf, player_faction)) = player_q.single() else { return; };
     let p = p_tf.translation().truncate();
+    let default_table = ReactionTable::default();
+    let table = reaction_handle
+        .as_ref()
+        .and_then(|h| reaction_tables.get(&h.0))
+        .unwrap_or(&default_table);
 
-    let trigger_dist = TILE_SIZE * 0.75;
-    for (enemy_e, e_tf) in enemies_q.iter() {
-        let d = e_tf.translation().truncate().distance(p);
-        if d < trigger_dist {
-            state.active = Some(ActiveCombat { player: player_e, enemy: enemy_e, players_turn: true });
-            break;
+    for (enemy_e, e_tf, enemy, enemy_faction, stats, controller) in enemies_q.iter() {
+        // A dying/dead enemy (hp <= 0, playing its Death clip) is about to be despawned by
+        // handle_enemy_death_cleanup; picking it as a fresh target would flicker back into
+        // Combat with a corpse before cleanup runs.
+        if stats.hp <= 0 || matches!(controller.current_animation, AnimationType::Death) {
+            continue;
+        }
+        let e_pos = e_tf.translation().truncate();
+        let sight_dist = enemy.sight_range * TILE_SIZE;
+        if e_pos.distance(p) > sight_dist {
+            continue;
         }
+        if !spatial_index.line_of_sight(e_pos, p) {
+            continue;
+        }
+
+        // Faction tags are backfilled by faction::ensure_faction the frame after spawn; until
+        // both sides have one, treat the pair as Neutral rather than assuming hostility.
+        let reaction = match (player_faction, enemy_faction) {
+            (Some(pf), Some(ef)) => table.reaction(&pf.0, &ef.0),
+            _ => Reaction::Neutral,
+        };
+        if reaction != Reaction::Hostile {
+            continue;
+        }
+
+        pending.0 = Some(ActiveCombatInfo { player: player_e, enemy: enemy_e });
+        next_phase.set(GamePhase::Combat);
+        break;
     }
 }
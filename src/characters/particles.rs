@@ -0,0 +1,196 @@
+// GPU particle bursts (bevy_hanabi) for jumps, hits, and deaths.
+//
+// Scope note: this tree has no Cargo.toml, so bevy_hanabi isn't an actual dependency here yet —
+// this module is written the way it would look once it is. Effect graphs are built once at
+// startup into `ParticleEffects`; the per-moment systems below just spawn a `ParticleEffect`
+// entity that plays one burst (the entity is not despawned afterwards), driven off the same signals
+// the rest of combat/movement already produce (DamageEvent, AnimationType transitions) rather
+// than anything hardcoded per call site.
+
+use bevy::prelude::*;
+use bevy_hanabi::prelude::*;
+
+use crate::characters::animation::{AnimationController, AnimationState};
+use crate::characters::config::AnimationType;
+use crate::characters::combat::DamageEvent;
+use crate::characters::movement::Player;
+
+/// Tunable knobs for the bursts below, so these can be retuned without touching the spawn
+/// systems themselves.
+#[derive(Resource, Debug, Clone)]
+pub struct ParticleConfig {
+    pub dust_particle_count: f32,
+    pub dust_lifetime_secs: f32,
+    pub spark_particle_count: f32,
+    pub spark_lifetime_secs: f32,
+    pub dissipate_particle_count: f32,
+    pub dissipate_lifetime_secs: f32,
+}
+
+impl Default for ParticleConfig {
+    fn default() -> Self {
+        Self {
+            dust_particle_count: 12.0,
+            dust_lifetime_secs: 0.35,
+            spark_particle_count: 20.0,
+            spark_lifetime_secs: 0.25,
+            dissipate_particle_count: 40.0,
+            dissipate_lifetime_secs: 0.6,
+        }
+    }
+}
+
+/// Handles to the three one-shot `EffectAsset`s built once at startup, so the spawn systems
+/// below just clone a handle instead of rebuilding an effect graph per burst.
+#[derive(Resource)]
+pub struct ParticleEffects {
+    jump_dust: Handle<EffectAsset>,
+    hit_spark: Handle<EffectAsset>,
+    death_dissipate: Handle<EffectAsset>,
+}
+
+/// Build a one-shot radial burst: `particle_count` particles spawned at once from the emitter
+/// origin, flying outward at `speed` and fading `color`'s alpha to zero over `lifetime_secs`.
+fn build_burst_effect(name: &str, color: Vec4, particle_count: f32, lifetime_secs: f32, speed: f32) -> EffectAsset {
+    let mut gradient = Gradient::new();
+    gradient.add_key(0.0, color);
+    gradient.add_key(1.0, color.with_w(0.0));
+
+    let writer = ExprWriter::new();
+    let init_age = SetAttributeModifier::new(Attribute::AGE, writer.lit(0.0).expr());
+    let init_lifetime = SetAttributeModifier::new(Attribute::LIFETIME, writer.lit(lifetime_secs).expr());
+    let init_pos = SetPositionSphereModifier {
+        center: writer.lit(Vec3::ZERO).expr(),
+        radius: writer.lit(2.0).expr(),
+        dimension: ShapeDimension::Volume,
+    };
+    let init_vel = SetVelocitySphereModifier {
+        center: writer.lit(Vec3::ZERO).expr(),
+        speed: writer.lit(speed).expr(),
+    };
+
+    EffectAsset::new(particle_count as u32, SpawnerSettings::once(particle_count.into()), writer.finish())
+        .with_name(name)
+        .init(init_pos)
+        .init(init_vel)
+        .init(init_age)
+        .init(init_lifetime)
+        .render(ColorOverLifetimeModifier { gradient })
+}
+
+/// Build the three effect graphs once; individual burst spawns below just reference their handle.
+pub fn setup_particle_effects(
+    mut commands: Commands,
+    mut effects: ResMut<Assets<EffectAsset>>,
+    config: Res<ParticleConfig>,
+) {
+    let jump_dust = effects.add(build_burst_effect(
+        "jump-dust",
+        Vec4::new(0.76, 0.70, 0.55, 0.8),
+        config.dust_particle_count,
+        config.dust_lifetime_secs,
+        40.0,
+    ));
+    let hit_spark = effects.add(build_burst_effect(
+        "hit-spark",
+        Vec4::new(1.0, 0.85, 0.2, 1.0),
+        config.spark_particle_count,
+        config.spark_lifetime_secs,
+        90.0,
+    ));
+    let death_dissipate = effects.add(build_burst_effect(
+        "death-dissipate",
+        Vec4::new(0.9, 0.1, 0.1, 0.6),
+        config.dissipate_particle_count,
+        config.dissipate_lifetime_secs,
+        60.0,
+    ));
+    commands.insert_resource(ParticleEffects { jump_dust, hit_spark, death_dissipate });
+}
+
+fn spawn_burst(commands: &mut Commands, effect: &Handle<EffectAsset>, at: Vec3) {
+    commands.spawn((
+        ParticleEffect::new(effect.clone()),
+        Transform::from_translation(at),
+    ));
+}
+
+/// Tracks the previous frame's `is_jumping` so the dust-burst system fires exactly on the
+/// takeoff/landing edges instead of every frame the player happens to be airborne.
+#[derive(Component, Default)]
+pub struct JumpDustTracker {
+    was_jumping: bool,
+}
+
+/// Every `Player` needs a `JumpDustTracker` to diff against; spawn.rs predates this subsystem, so
+/// backfill it here instead of threading another component through the player spawn bundle.
+pub fn ensure_jump_dust_tracker(
+    mut commands: Commands,
+    players: Query<Entity, (With<Player>, Without<JumpDustTracker>)>,
+) {
+    for entity in players.iter() {
+        commands.entity(entity).insert(JumpDustTracker::default());
+    }
+}
+
+/// Dust burst at the player's feet on jump takeoff and on landing.
+pub fn spawn_jump_dust(
+    mut commands: Commands,
+    effects: Res<ParticleEffects>,
+    mut players: Query<(&Transform, &AnimationState, &mut JumpDustTracker), With<Player>>,
+) {
+    for (transform, state, mut tracker) in players.iter_mut() {
+        if state.is_jumping != tracker.was_jumping {
+            spawn_burst(&mut commands, &effects.jump_dust, transform.translation);
+        }
+        tracker.was_jumping = state.is_jumping;
+    }
+}
+
+/// Spark/impact spray wherever a `DamageEvent` lands. Reusing `DamageEvent` instead of
+/// change-detecting `CombatStats.hp` directly means we only ever see real hp drops (not heals,
+/// and not the Changed<CombatStats> false-positives that fire on unrelated field writes) and we
+/// get the target entity for free instead of needing a "previous hp" snapshot component.
+pub fn spawn_hit_sparks(
+    mut commands: Commands,
+    effects: Res<ParticleEffects>,
+    mut damage_events: EventReader<DamageEvent>,
+    transforms: Query<&GlobalTransform>,
+) {
+    for event in damage_events.read() {
+        let Ok(gt) = transforms.get(event.target) else { continue; };
+        spawn_burst(&mut commands, &effects.hit_spark, gt.translation());
+    }
+}
+
+/// Tracks whether an entity was already playing its Death animation, so the dissipation burst
+/// fires once on the entry edge rather than every frame of the clip.
+#[derive(Component, Default)]
+pub struct DeathFxTracker {
+    was_dying: bool,
+}
+
+/// Any animated entity (player or enemy) needs a `DeathFxTracker` to diff against.
+pub fn ensure_death_fx_tracker(
+    mut commands: Commands,
+    animated: Query<Entity, (With<AnimationState>, Without<DeathFxTracker>)>,
+) {
+    for entity in animated.iter() {
+        commands.entity(entity).insert(DeathFxTracker::default());
+    }
+}
+
+/// Dissipation burst the frame an entity's animation transitions into `AnimationType::Death`.
+pub fn spawn_death_dissipation(
+    mut commands: Commands,
+    effects: Res<ParticleEffects>,
+    mut animated: Query<(&Transform, &AnimationController, &mut DeathFxTracker)>,
+) {
+    for (transform, controller, mut tracker) in animated.iter_mut() {
+        let is_dying = matches!(controller.current_animation, AnimationType::Death);
+        if is_dying && !tracker.was_dying {
+            spawn_burst(&mut commands, &effects.death_dissipate, transform.translation);
+        }
+        tracker.was_dying = is_dying;
+    }
+}
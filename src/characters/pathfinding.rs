@@ -0,0 +1,266 @@
+// Tile-grid A* pathfinding so enemies chase the player across NonWalkable/Water terrain.
+// The walkability grid is built from the same NonWalkable/Water tiles map::collision gives
+// static rapier colliders; actual movement sweeps against those colliders via a
+// KinematicCharacterController, the same way characters::movement::move_player does.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::characters::config::CharacterEntry;
+use crate::characters::movement::Player;
+use crate::characters::npc::Enemy;
+use crate::map::generate::TILE_SIZE;
+use crate::map::spatial::SpatialIndex;
+
+/// Half-extent of an enemy's collider, matching the player's in characters::movement.
+const ENEMY_COLLIDER_HALF_EXTENT: f32 = TILE_SIZE * 0.35;
+
+/// How far (in tiles, per axis) an enemy will search for the player before giving up.
+const MAX_SEARCH_RADIUS_TILES: i32 = 40;
+/// Cap on cells expanded per search so one enemy's search can't spike the frame.
+const MAX_VISITED_CELLS: usize = 2000;
+/// Re-run A* at least this often; recomputed sooner if the player changes tile or the path breaks.
+const REPATH_INTERVAL_SECS: f32 = 0.5;
+/// Fallback straight-line speed used while no path is cached yet.
+const DEFAULT_CHASE_SPEED: f32 = 80.0;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct Cell {
+    x: i32,
+    y: i32,
+}
+
+fn world_to_cell(pos: Vec2) -> Cell {
+    Cell { x: (pos.x / TILE_SIZE).floor() as i32, y: (pos.y / TILE_SIZE).floor() as i32 }
+}
+
+fn cell_to_world(cell: Cell) -> Vec2 {
+    Vec2::new((cell.x as f32 + 0.5) * TILE_SIZE, (cell.y as f32 + 0.5) * TILE_SIZE)
+}
+
+/// Walkability grid built once from NonWalkable/Water tiles and shared by every enemy's A*
+/// search, instead of each enemy re-querying every tile in the world per frame.
+#[derive(Resource, Default)]
+pub struct WalkGrid {
+    blocked: HashSet<Cell>,
+    built: bool,
+}
+
+impl WalkGrid {
+    fn is_blocked(&self, cell: Cell) -> bool {
+        self.blocked.contains(&cell)
+    }
+}
+
+/// Populate `WalkGrid` from map::spatial::SpatialIndex once it's built, rather than re-scanning
+/// every NonWalkable/Water tile's GlobalTransform itself.
+pub fn build_walk_grid(mut grid: ResMut<WalkGrid>, spatial_index: Res<SpatialIndex>) {
+    if grid.built {
+        return;
+    }
+    if !spatial_index.is_built() {
+        return;
+    }
+
+    for cell in spatial_index.blocked_cells() {
+        grid.blocked.insert(Cell { x: cell.x, y: cell.y });
+    }
+    grid.built = true;
+}
+
+#[derive(Copy, Clone, PartialEq)]
+struct ScoredCell {
+    cell: Cell,
+    f_score: f32,
+}
+impl Eq for ScoredCell {}
+impl Ord for ScoredCell {
+    // Reversed so BinaryHeap (a max-heap) pops the lowest f_score first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.f_score.partial_cmp(&self.f_score).unwrap_or(Ordering::Equal)
+    }
+}
+impl PartialOrd for ScoredCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+const NEIGHBOR_OFFSETS: [(i32, i32, f32); 8] = [
+    (1, 0, 1.0),
+    (-1, 0, 1.0),
+    (0, 1, 1.0),
+    (0, -1, 1.0),
+    (1, 1, std::f32::consts::SQRT_2),
+    (1, -1, std::f32::consts::SQRT_2),
+    (-1, 1, std::f32::consts::SQRT_2),
+    (-1, -1, std::f32::consts::SQRT_2),
+];
+
+fn octile_heuristic(a: Cell, b: Cell) -> f32 {
+    let dx = (a.x - b.x).abs() as f32;
+    let dy = (a.y - b.y).abs() as f32;
+    let (dmin, dmax) = if dx < dy { (dx, dy) } else { (dy, dx) };
+    dmax - dmin + dmin * std::f32::consts::SQRT_2
+}
+
+fn reconstruct_path(came_from: &HashMap<Cell, Cell>, mut cell: Cell) -> Vec<Cell> {
+    let mut path = vec![cell];
+    while let Some(&prev) = came_from.get(&cell) {
+        cell = prev;
+        path.push(cell);
+    }
+    path.reverse();
+    path.remove(0); // drop the starting cell; callers only need cells to move through
+    path
+}
+
+/// 8-connected A* from `start` to `goal` over `grid`. Returns the waypoint cells (excluding
+/// `start`) in travel order, or `None` if the goal is blocked, out of search range, or the
+/// visited-cell cap is hit before the goal is found.
+fn find_path(grid: &WalkGrid, start: Cell, goal: Cell) -> Option<Vec<Cell>> {
+    if grid.is_blocked(goal) {
+        return None;
+    }
+    if (start.x - goal.x).abs() > MAX_SEARCH_RADIUS_TILES
+        || (start.y - goal.y).abs() > MAX_SEARCH_RADIUS_TILES
+    {
+        return None;
+    }
+
+    let mut open = BinaryHeap::new();
+    let mut came_from: HashMap<Cell, Cell> = HashMap::new();
+    let mut g_score: HashMap<Cell, f32> = HashMap::new();
+    g_score.insert(start, 0.0);
+    open.push(ScoredCell { cell: start, f_score: octile_heuristic(start, goal) });
+
+    let mut visited = 0usize;
+    while let Some(ScoredCell { cell, .. }) = open.pop() {
+        if cell == goal {
+            return Some(reconstruct_path(&came_from, cell));
+        }
+
+        visited += 1;
+        if visited > MAX_VISITED_CELLS {
+            return None; // clamp search size; caller falls back to direct steering
+        }
+
+        let current_g = g_score[&cell];
+        for (dx, dy, cost) in NEIGHBOR_OFFSETS {
+            let neighbor = Cell { x: cell.x + dx, y: cell.y + dy };
+            if grid.is_blocked(neighbor) {
+                continue;
+            }
+            let tentative_g = current_g + cost;
+            if tentative_g < *g_score.get(&neighbor).unwrap_or(&f32::INFINITY) {
+                came_from.insert(neighbor, cell);
+                g_score.insert(neighbor, tentative_g);
+                open.push(ScoredCell { cell: neighbor, f_score: tentative_g + octile_heuristic(neighbor, goal) });
+            }
+        }
+    }
+    None
+}
+
+/// Cached A* path for one enemy. Recomputed only when it goes stale, per `is_stale`, rather than
+/// every frame.
+#[derive(Component, Default)]
+pub struct EnemyPath {
+    waypoints: VecDeque<Vec2>,
+    target_cell: Option<Cell>,
+    repath_timer: f32,
+}
+
+impl EnemyPath {
+    fn is_stale(&self, player_cell: Cell, grid: &WalkGrid) -> bool {
+        self.repath_timer <= 0.0
+            || self.target_cell != Some(player_cell)
+            || self.waypoints.front().is_some_and(|&wp| grid.is_blocked(world_to_cell(wp)))
+    }
+}
+
+/// Give an enemy a kinematic physics body the first time it's seen without one, mirroring
+/// characters::movement::ensure_player_physics_body.
+pub fn ensure_enemy_physics_body(
+    mut commands: Commands,
+    enemies: Query<(Entity, Option<&CharacterEntry>), (With<Enemy>, Without<KinematicCharacterController>)>,
+) {
+    for (entity, character) in enemies.iter() {
+        // Scale by the enemy's tile footprint the same way ensure_player_physics_body does, so a
+        // multi-tile enemy (e.g. a 2x2 boss) gets a collider matching its visual size.
+        let footprint = character.map(|c| c.footprint).unwrap_or_default();
+        let half = Vec2::new(footprint.width as f32, footprint.height as f32) * ENEMY_COLLIDER_HALF_EXTENT;
+        commands.entity(entity).insert((
+            RigidBody::KinematicPositionBased,
+            Collider::cuboid(half.x, half.y),
+            KinematicCharacterController::default(),
+        ));
+    }
+}
+
+/// Drive each Enemy toward the Player along a cached A* path, recomputing when the player
+/// crosses a tile boundary, the cached path becomes blocked, or REPATH_INTERVAL_SECS has elapsed
+/// since the last search. Falls back to direct steering when
+/// no path exists (unreachable goal, search radius exceeded, or the visited-cell cap was hit), and
+/// to standing still when the player is outside the enemy's aggro radius (its `sight_range`, the
+/// same field and same line-of-sight check npc::detect_player_proximity_start_combat uses) so
+/// enemies don't home in on the player through walls before combat would even trigger.
+/// Actual collision sweeping is rapier's kinematic character controller, the same one
+/// characters::movement::move_player uses, so enemies don't tunnel through thin obstacles either.
+pub fn chase_player(
+    time: Res<Time>,
+    grid: Res<WalkGrid>,
+    spatial_index: Res<SpatialIndex>,
+    player_q: Query<&GlobalTransform, With<Player>>,
+    mut enemies_q: Query<(&Transform, &mut KinematicCharacterController, &mut EnemyPath, &Enemy, Option<&CharacterEntry>), With<Enemy>>,
+) {
+    if !grid.built {
+        return;
+    }
+    let Ok(player_tf) = player_q.single() else { return; };
+    let player_pos = player_tf.translation().truncate();
+    let player_cell = world_to_cell(player_pos);
+
+    for (transform, mut controller, mut path, enemy, character) in enemies_q.iter_mut() {
+        path.repath_timer -= time.delta_secs();
+
+        let enemy_pos = transform.translation.truncate();
+        let enemy_cell = world_to_cell(enemy_pos);
+
+        let aggro_dist = enemy.sight_range * TILE_SIZE;
+        let aggroed = enemy_pos.distance(player_pos) <= aggro_dist
+            && spatial_index.line_of_sight(enemy_pos, player_pos);
+        if !aggroed {
+            controller.translation = None;
+            continue;
+        }
+
+        if path.is_stale(player_cell, &grid) {
+            path.waypoints = find_path(&grid, enemy_cell, player_cell)
+                .map(|cells| cells.into_iter().map(cell_to_world).collect())
+                .unwrap_or_default();
+            path.target_cell = Some(player_cell);
+            path.repath_timer = REPATH_INTERVAL_SECS;
+        }
+
+        // Drop waypoints already reached so the enemy advances along the path instead of
+        // orbiting the first cell forever.
+        while path.waypoints.front().is_some_and(|&wp| wp.distance(enemy_pos) < TILE_SIZE * 0.25) {
+            path.waypoints.pop_front();
+        }
+
+        // No cached waypoint (no path found) falls back to steering straight at the player.
+        let steer_target = path.waypoints.front().copied().unwrap_or(player_pos);
+        let to_target = steer_target - enemy_pos;
+        if to_target.length() < 1.0 {
+            controller.translation = None;
+            continue;
+        }
+
+        let speed = character.map(|c| c.base_move_speed).unwrap_or(DEFAULT_CHASE_SPEED);
+        controller.translation = Some(to_target.normalize() * speed * time.delta_secs());
+    }
+}
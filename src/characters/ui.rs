@@ -1,7 +1,15 @@
 // A simple UI/HUD showing player HP and controls
+//
+// Layout (bar size/colors, per-element anchors/offsets, font size, controls text) is data-driven
+// from a HudConfig RON asset instead of being hardcoded separately in spawn_hud_once and
+// position_hud_to_camera, so the two can't drift out of sync and the HUD can be reskinned without
+// touching either function.
+
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
 
-use crate::characters::combat::{CombatState, CombatStats};
+use crate::characters::combat::{ActiveCombat, CombatStats};
 use crate::characters::npc::Enemy;
 
 // Simple, always-on HUD showing player HP and controls
@@ -20,55 +28,165 @@ pub struct HudControlsText;
 
 const HUD_Z: f32 = 55.0; // below combat UI (60+) and overlays (70+)
 
+/// Screen corner/edge an offset is measured from, resolved against the camera position and
+/// window half-size rather than a fixed world offset, so the HUD stays pinned to the same corner
+/// across window resolutions.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum HudAnchor {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+impl HudAnchor {
+    fn resolve(self, cam_pos: Vec2, half_window: Vec2) -> Vec2 {
+        let (x, y) = match self {
+            HudAnchor::TopLeft => (-half_window.x, half_window.y),
+            HudAnchor::TopCenter => (0.0, half_window.y),
+            HudAnchor::TopRight => (half_window.x, half_window.y),
+            HudAnchor::BottomLeft => (-half_window.x, -half_window.y),
+            HudAnchor::BottomCenter => (0.0, -half_window.y),
+            HudAnchor::BottomRight => (half_window.x, -half_window.y),
+        };
+        cam_pos + Vec2::new(x, y)
+    }
+}
+
+// Bundle every HUD magic number that used to live separately in spawn_hud_once and
+// position_hud_to_camera. Loaded as a RON asset the same way CharactersList is.
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+pub struct HudConfig {
+    pub bar_width: f32,
+    pub bar_height: f32,
+    // How much smaller the fill sprite is than its background frame, per axis, so the green/red
+    // fill never pokes out past the grey frame.
+    pub bar_fill_inset: f32,
+    pub bar_bg_color: [f32; 3],
+    pub player_fill_color: [f32; 3],
+    pub enemy_fill_color: [f32; 3],
+    pub bars_anchor: HudAnchor,
+    pub bars_offset_x: f32,
+    pub bars_offset_y: f32,
+    // Player/enemy bar positions relative to the anchored base, not the screen.
+    pub player_bar_offset_x: f32,
+    pub enemy_bar_offset_x: f32,
+    pub controls_anchor: HudAnchor,
+    pub controls_offset_x: f32,
+    pub controls_offset_y: f32,
+    pub controls_font_size: f32,
+    pub controls_text: String,
+}
+
+/// Fallback layout used until hud.ron finishes loading. Bar size, colors and font size match the
+/// values this HUD shipped with before it became data-driven; positions are anchored to the
+/// window edges and do not reproduce the old fixed camera offsets.
+fn default_hud_config() -> HudConfig {
+    HudConfig {
+        bar_width: 220.0,
+        bar_height: 16.0,
+        bar_fill_inset: 6.0,
+        bar_bg_color: [0.2, 0.2, 0.2],
+        player_fill_color: [0.1, 0.8, 0.1],
+        enemy_fill_color: [0.8, 0.1, 0.1],
+        bars_anchor: HudAnchor::TopCenter,
+        bars_offset_x: 0.0,
+        bars_offset_y: -60.0,
+        player_bar_offset_x: -130.0,
+        enemy_bar_offset_x: 130.0,
+        controls_anchor: HudAnchor::TopLeft,
+        controls_offset_x: 60.0,
+        controls_offset_y: -140.0,
+        controls_font_size: 16.0,
+        controls_text: " Controls:\n- Characters: 1-6 \n- Move: WASD / Arrows\n- Run: Shift\n- Jump: Space\n- Combat: Space / Enter\n- Restart: R\n- Quit: Q".to_string(),
+    }
+}
+
+/// Handle to the loaded hud.ron asset, set once at startup.
+#[derive(Resource)]
+pub struct HudConfigHandle(pub Handle<HudConfig>);
+
+pub fn load_hud_config(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(HudConfigHandle(asset_server.load("hud.ron")));
+}
+
+fn current_hud_config<'a>(handle: &HudConfigHandle, configs: &'a Assets<HudConfig>, fallback: &'a HudConfig) -> &'a HudConfig {
+    configs.get(&handle.0).unwrap_or(fallback)
+}
+
+fn half_window_size(windows: &Query<&Window, With<PrimaryWindow>>) -> Vec2 {
+    windows.single().map(|w| Vec2::new(w.width(), w.height()) * 0.5).unwrap_or(Vec2::new(640.0, 360.0))
+}
+
 pub fn spawn_hud_once(
     mut commands: Commands,
     existing: Query<Entity, With<HudRoot>>,
     cam_q: Query<&Transform, With<Camera2d>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    handle: Option<Res<HudConfigHandle>>,
+    configs: Res<Assets<HudConfig>>,
 ) {
     if existing.iter().next().is_some() { return; }
-    let cam_pos = cam_q.single().map(|t| t.translation).unwrap_or(Vec3::ZERO);
+    let cam_pos = cam_q.single().map(|t| t.translation.truncate()).unwrap_or(Vec2::ZERO);
+    let half_window = half_window_size(&windows);
+
+    let fallback = default_hud_config();
+    let config = handle.as_ref().map(|h| current_hud_config(h, &configs, &fallback)).unwrap_or(&fallback);
+
+    let bars_base = config.bars_anchor.resolve(cam_pos, half_window) + Vec2::new(config.bars_offset_x, config.bars_offset_y);
+    let base = bars_base.extend(HUD_Z);
+
+    let bg_color = Color::srgb(config.bar_bg_color[0], config.bar_bg_color[1], config.bar_bg_color[2]);
+    let bg_size = Vec2::new(config.bar_width, config.bar_height);
+    let fill_size = Vec2::new(config.bar_width - config.bar_fill_inset, config.bar_height - config.bar_fill_inset);
 
     // Root marker (no parenting to keep it simple with camera-follow)
-    // Place the health bars at the top of the window
-    let base = Vec3::new(cam_pos.x, cam_pos.y + 220.0, HUD_Z);
     commands.spawn((Transform::from_translation(base), HudRoot));
 
     // Health bar background frames (grey) — Player left, Enemy right
     commands.spawn((
-        Sprite { color: Color::srgb(0.2, 0.2, 0.2), custom_size: Some(Vec2::new(220.0, 16.0)), ..default() },
-        Transform::from_translation(base + Vec3::new(-130.0, 0.0, 0.5)),
+        Sprite { color: bg_color, custom_size: Some(bg_size), ..default() },
+        Transform::from_translation(base + Vec3::new(config.player_bar_offset_x, 0.0, 0.5)),
         HudHealthBg,
     ));
     commands.spawn((
-        Sprite { color: Color::srgb(0.2, 0.2, 0.2), custom_size: Some(Vec2::new(220.0, 16.0)), ..default() },
-        Transform::from_translation(base + Vec3::new(130.0, 0.0, 0.5)),
+        Sprite { color: bg_color, custom_size: Some(bg_size), ..default() },
+        Transform::from_translation(base + Vec3::new(config.enemy_bar_offset_x, 0.0, 0.5)),
         HudEnemyHealthBg,
     ));
 
     // Player health bar fill (green), width adjusted in update
     commands.spawn((
-        Sprite { color: Color::srgb(0.1, 0.8, 0.1), custom_size: Some(Vec2::new(214.0, 12.0)), ..default() },
-        Transform::from_translation(base + Vec3::new(-130.0, 0.0, 0.6)),
+        Sprite {
+            color: Color::srgb(config.player_fill_color[0], config.player_fill_color[1], config.player_fill_color[2]),
+            custom_size: Some(fill_size),
+            ..default()
+        },
+        Transform::from_translation(base + Vec3::new(config.player_bar_offset_x, 0.0, 0.6)),
         HudHealthFill,
     ));
 
     // Enemy health bar fill (red), width adjusted in update (hidden if no combat)
     commands.spawn((
-        Sprite { color: Color::srgb(0.8, 0.1, 0.1), custom_size: Some(Vec2::new(0.0, 12.0)), ..default() },
-        Transform::from_translation(base + Vec3::new(130.0, 0.0, 0.6)),
+        Sprite {
+            color: Color::srgb(config.enemy_fill_color[0], config.enemy_fill_color[1], config.enemy_fill_color[2]),
+            custom_size: Some(Vec2::new(0.0, fill_size.y)),
+            ..default()
+        },
+        Transform::from_translation(base + Vec3::new(config.enemy_bar_offset_x, 0.0, 0.6)),
         HudEnemyHealthFill,
     ));
 
-    // Controls legend text as a column on the left side
-    let controls = " Controls:\n- Characters: 1-6 \n- Move: WASD / Arrows\n- Run: Shift\n- Jump: Space\n- Combat: Space / Enter\n- Restart: R\n- Quit: Q";
+    // Controls legend text, anchored independently of the health bars
+    let controls_pos = config.controls_anchor.resolve(cam_pos, half_window) + Vec2::new(config.controls_offset_x, config.controls_offset_y);
     commands.spawn((
-        Text2d::new(controls.to_string()),
-        TextFont { font_size: 16.0, ..Default::default() },
-        // Increase contrast: use white text
+        Text2d::new(config.controls_text.clone()),
+        TextFont { font_size: config.controls_font_size, ..Default::default() },
         TextColor(Color::WHITE),
         TextLayout { justify: Justify::Left, ..Default::default() },
-        // Position on the left side of the screen, below the top bars
-        Transform::from_translation(Vec3::new(cam_pos.x - 360.0, cam_pos.y + 140.0, HUD_Z + 0.6)),
+        Transform::from_translation(controls_pos.extend(HUD_Z + 0.6)),
         HudControlsText,
     ));
 }
@@ -76,9 +194,12 @@ pub fn spawn_hud_once(
 // Follow the current camera so HUD sticks to the screen corners
 pub fn position_hud_to_camera(
     cam_q: Query<&Transform, With<Camera2d>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    handle: Option<Res<HudConfigHandle>>,
+    configs: Res<Assets<HudConfig>>,
     mut transforms: ParamSet<(
-        Query<&'static mut Transform, (With<HudRoot>, Without<Camera2d>)>, 
-        Query<&'static mut Transform, (With<HudHealthFill>, Without<HudRoot>, Without<Camera2d>)>, 
+        Query<&'static mut Transform, (With<HudRoot>, Without<Camera2d>)>,
+        Query<&'static mut Transform, (With<HudHealthFill>, Without<HudRoot>, Without<Camera2d>)>,
         Query<&'static mut Transform, (With<HudEnemyHealthFill>, Without<HudRoot>, Without<HudHealthFill>, Without<Camera2d>)>,
         Query<&'static mut Transform, (With<HudHealthBg>, Without<HudRoot>, Without<HudHealthFill>, Without<HudEnemyHealthFill>, Without<Camera2d>)>,
         Query<&'static mut Transform, (With<HudEnemyHealthBg>, Without<HudRoot>, Without<HudHealthFill>, Without<HudEnemyHealthFill>, Without<Camera2d>)>,
@@ -86,59 +207,66 @@ pub fn position_hud_to_camera(
     )>,
 ) {
     let Ok(cam) = cam_q.single() else { return; };
-    let cam_pos = cam.translation;
-    // Base for the top health bars
-    let base = Vec3::new(cam_pos.x, cam_pos.y + 220.0, HUD_Z);
+    let cam_pos = cam.translation.truncate();
+    let half_window = half_window_size(&windows);
+
+    let fallback = default_hud_config();
+    let config = handle.as_ref().map(|h| current_hud_config(h, &configs, &fallback)).unwrap_or(&fallback);
+
+    let bars_base = config.bars_anchor.resolve(cam_pos, half_window) + Vec2::new(config.bars_offset_x, config.bars_offset_y);
+    let base = bars_base.extend(HUD_Z);
 
-    // Move root and dependent elements together by computing offsets from base
     if let Ok(mut root_tf) = transforms.p0().single_mut() {
         root_tf.translation = base;
     }
-
-    // Realign children relative to base (they are unparented for simplicity)
     if let Ok(mut tf) = transforms.p1().single_mut() { // player fill
-        tf.translation = base + Vec3::new(-130.0, 0.0, 0.6);
+        tf.translation = base + Vec3::new(config.player_bar_offset_x, 0.0, 0.6);
     }
     if let Ok(mut tf) = transforms.p2().single_mut() { // enemy fill
-        tf.translation = base + Vec3::new(130.0, 0.0, 0.6);
+        tf.translation = base + Vec3::new(config.enemy_bar_offset_x, 0.0, 0.6);
     }
     if let Ok(mut tf) = transforms.p3().single_mut() { // player bg
-        tf.translation = base + Vec3::new(-130.0, 0.0, 0.5);
+        tf.translation = base + Vec3::new(config.player_bar_offset_x, 0.0, 0.5);
     }
     if let Ok(mut tf) = transforms.p4().single_mut() { // enemy bg
-        tf.translation = base + Vec3::new(130.0, 0.0, 0.5);
+        tf.translation = base + Vec3::new(config.enemy_bar_offset_x, 0.0, 0.5);
     }
-    if let Ok(mut tf) = transforms.p5().single_mut() { // controls (left column)
-        // Position controls down and to the left from the top bars
-        let left_pos = Vec3::new(cam_pos.x - 300.0, cam_pos.y + 140.0, HUD_Z + 0.6);
-        tf.translation = left_pos;
+    if let Ok(mut tf) = transforms.p5().single_mut() { // controls column
+        let controls_pos = config.controls_anchor.resolve(cam_pos, half_window) + Vec2::new(config.controls_offset_x, config.controls_offset_y);
+        tf.translation = controls_pos.extend(HUD_Z + 0.6);
     }
 }
 
 // Update HUD HP bars (player always, enemy only during active combat)
 pub fn update_hud_health(
+    handle: Option<Res<HudConfigHandle>>,
+    configs: Res<Assets<HudConfig>>,
     player_q: Query<&CombatStats, With<crate::characters::movement::Player>>,
     enemy_q: Query<&CombatStats, (With<Enemy>, Without<crate::characters::movement::Player>)>,
-    state: Res<CombatState>,
+    active: Option<Res<ActiveCombat>>,
     mut sprite_sets: ParamSet<(
         Query<&'static mut Sprite, With<HudHealthFill>>,
         Query<&'static mut Sprite, (With<HudEnemyHealthFill>, Without<HudHealthFill>)>,
     )>,
 ) {
+    let fallback = default_hud_config();
+    let config = handle.as_ref().map(|h| current_hud_config(h, &configs, &fallback)).unwrap_or(&fallback);
+    let fill_width = config.bar_width - config.bar_fill_inset;
+
     let Ok(pstats) = player_q.single() else { return; };
     if let Ok(mut fill) = sprite_sets.p0().single_mut() {
         let ratio = (pstats.hp.max(0) as f32) / (pstats.max_hp.max(1) as f32);
         if let Some(size) = &mut fill.custom_size {
-            size.x = 214.0 * ratio.clamp(0.0, 1.0);
+            size.x = fill_width * ratio.clamp(0.0, 1.0);
         }
     }
 
     // Enemy bar reflects current combat target if combat is active; else hidden
-    if let Some(active) = state.active.as_ref() {
+    if let Some(active) = active.as_ref() {
         if let (Ok(estats), Ok(mut efill)) = (enemy_q.get(active.enemy), sprite_sets.p1().single_mut()) {
             let ratio = (estats.hp.max(0) as f32) / (estats.max_hp.max(1) as f32);
             if let Some(size) = &mut efill.custom_size {
-                size.x = 214.0 * ratio.clamp(0.0, 1.0);
+                size.x = fill_width * ratio.clamp(0.0, 1.0);
             }
         }
     } else if let Ok(mut efill) = sprite_sets.p1().single_mut() {
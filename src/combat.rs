@@ -2,6 +2,8 @@
 
 use bevy::prelude::*;
 
+use serde::{Deserialize, Serialize};
+
 use crate::characters::movement::Player;
 use crate::characters::config::{CharacterEntry, AnimationType};
 use crate::characters::npc::{EnemyTracker, Enemy};
@@ -9,6 +11,20 @@ use crate::characters::animation::Facing;
 
 // Enemy component is defined in npc.rs
 
+// What kind of damage an attack deals. Physical is reduced by the target's `defense`;
+// elemental types bypass defense entirely but can instead apply a lingering StatusEffect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DamageType {
+    Physical,
+    Fire,
+    Poison,
+    Ice,
+}
+
+impl Default for DamageType {
+    fn default() -> Self { DamageType::Physical }
+}
+
 #[derive(Component, Debug, Clone, Copy)]
 pub struct CombatStats {
     pub max_hp: i32,
@@ -17,32 +33,172 @@ pub struct CombatStats {
     pub defense: i32,
     pub crit_chance: f32,  // 0.0..1.0
     pub evade_chance: f32, // 0.0..1.0
+    pub damage_type: DamageType,
 }
 impl CombatStats {
 }
 
-#[derive(Resource, Default)]
-pub struct CombatState {
-    pub active: Option<ActiveCombat>,
+// A simple current/max resource pool, shared shape for mana and any future pool (stamina, etc).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Pool {
+    pub current: i32,
+    pub max: i32,
+}
+
+// Player-only progression: XP accrued from defeated enemies, the level it unlocks, and the mana
+// pool that scales with it. Kept separate from CombatStats (which enemies also use) since none of
+// this applies to them. See handle_enemy_death_cleanup for how XP is awarded and levels applied.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Progression {
+    pub xp: i32,
+    pub level: i32,
+    pub mana: Pool,
+    // Fractional mana regen accrued since the last whole point was credited to mana.current; see
+    // regen_player_mana. Without this, rounding a per-frame regen trickle straight into an i32
+    // every frame would discard it entirely whenever delta_secs * mana_regen < 1.0.
+    mana_regen_accum: f32,
+}
+
+// XP required to reach the next level, scaling linearly with the current level.
+const LEVEL_XP_THRESHOLD: i32 = 100;
+// Stat gains applied on every level-up.
+const LEVEL_UP_MAX_HP_GAIN: i32 = 10;
+const LEVEL_UP_MAX_MANA_GAIN: i32 = 10;
+
+// XP an enemy is worth, proportional to how tough and hard-hitting it was.
+fn xp_reward(config: &CharacterEntry) -> i32 {
+    (config.max_health * 0.5 + config.attack_damage * 2.0).round() as i32
+}
+
+// Applies any level-ups earned by `progression.xp`, refilling both pools each time one lands.
+// A `while` (not `if`) so a single large XP award can cross more than one threshold at once.
+fn apply_level_ups(progression: &mut Progression, stats: &mut CombatStats) {
+    let mut threshold = progression.level.max(1) * LEVEL_XP_THRESHOLD;
+    while progression.xp >= threshold {
+        progression.xp -= threshold;
+        progression.level += 1;
+        stats.max_hp += LEVEL_UP_MAX_HP_GAIN;
+        stats.hp = stats.max_hp;
+        progression.mana.max += LEVEL_UP_MAX_MANA_GAIN;
+        progression.mana.current = progression.mana.max;
+        progression.mana_regen_accum = 0.0;
+        threshold = progression.level * LEVEL_XP_THRESHOLD;
+    }
+}
+
+// Status effects applied by elemental attacks. Ticked once per combat turn by
+// `tick_status_effects`, which emits a DamageEvent (and a GameLog line) for each tick.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Burn { pub dmg_per_turn: i32, pub turns_left: u32 }
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Poison { pub dmg_per_turn: i32, pub turns_left: u32 }
+
+// Chilled halves the afflicted entity's hit chance on its own turns rather than dealing damage.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Chilled { pub turns_left: u32 }
+
+// One equippable attack: its own damage/crit/accuracy and an optional elemental affinity, loaded
+// straight from `CharacterEntry.abilities` in the character's RON file.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Weapon {
+    pub name: String,
+    pub base_damage: i32,
+    pub crit_chance: f32,
+    // Added to the base 0.85 hit chance before the target's evade_chance is subtracted; lets
+    // e.g. a heavy weapon trade accuracy for damage.
+    pub hit_modifier: f32,
+    #[serde(default)]
+    pub damage_type: DamageType,
+    // How many of the wielder's own turns this ability is locked out for after use.
+    #[serde(default)]
+    pub cooldown_turns: u32,
+    // Mana deducted from the wielder's Progression.mana on use. Absent (0) means the ability is
+    // free, matching every ability authored before mana costs existed.
+    #[serde(default)]
+    pub cost: i32,
+}
+
+// The set of abilities a combatant can choose from during their turn, plus how long each is
+// still on cooldown. `selected` is the index number keys 1-4 change during the player's turn.
+#[derive(Component, Debug, Clone)]
+pub struct Abilities {
+    pub weapons: Vec<Weapon>,
+    cooldowns: Vec<u32>,
+    pub selected: usize,
+}
+
+impl Abilities {
+    pub fn new(weapons: Vec<Weapon>) -> Self {
+        let cooldowns = vec![0; weapons.len()];
+        Self { weapons, cooldowns, selected: 0 }
+    }
+
+    pub fn is_ready(&self, idx: usize) -> bool {
+        self.cooldowns.get(idx).copied().unwrap_or(0) == 0
+    }
+
+    // Off cooldown *and* affordable given `mana` currently available.
+    pub fn can_use(&self, idx: usize, mana: i32) -> bool {
+        self.is_ready(idx) && self.weapons.get(idx).is_some_and(|w| w.cost <= mana)
+    }
+
+    pub fn cooldown_remaining(&self, idx: usize) -> u32 {
+        self.cooldowns.get(idx).copied().unwrap_or(0)
+    }
+
+    // Puts the used ability on its own cooldown; call once per turn it's actually used.
+    pub fn use_ability(&mut self, idx: usize) {
+        if let (Some(cd), Some(weapon)) = (self.cooldowns.get_mut(idx), self.weapons.get(idx)) {
+            *cd = weapon.cooldown_turns;
+        }
+    }
+
+    // Called once at the start of the owner's turn so cooldowns count down in turns, not frames.
+    pub fn tick_cooldowns(&mut self) {
+        for cd in &mut self.cooldowns {
+            *cd = cd.saturating_sub(1);
+        }
+    }
+
+    // Clears every cooldown; used when a run restarts so leftover cooldowns don't carry over.
+    pub fn reset_cooldowns(&mut self) {
+        for cd in &mut self.cooldowns {
+            *cd = 0;
+        }
+    }
+}
+
+// Drives overall game flow. Combat systems are scheduled with run_if(in_state(GamePhase::Combat))
+// instead of guarding on a resource being Some/None, and the combat UI / outcome overlays are
+// spawned and despawned from OnEnter/OnExit so no system has to re-check "did this already happen".
+#[derive(States, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum GamePhase {
+    #[default]
+    Exploring,
+    Combat,
+    GameOver,
+    Won,
 }
 
 #[derive(Debug)]
-pub struct ActiveCombat {
+pub struct ActiveCombatInfo {
     pub player: Entity,
     pub enemy: Entity,
-    pub players_turn: bool,
 }
 
-// Overall game outcome
-#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
-pub enum GameOutcome {
-    None,
-    GameOver,
-    GameWon,
-}
+// Handed from detect_player_proximity_start_combat to the OnEnter(Combat) system, since OnEnter
+// systems can't take parameters directly; cleared once consumed.
+#[derive(Resource, Default)]
+pub struct PendingCombat(pub Option<ActiveCombatInfo>);
 
-impl Default for GameOutcome {
-    fn default() -> Self { GameOutcome::None }
+// Only exists as a resource while GamePhase::Combat is active; inserted in OnEnter and removed
+// in OnExit so combat systems can take it as a plain Res/ResMut instead of unwrapping an Option.
+#[derive(Resource, Debug)]
+pub struct ActiveCombat {
+    pub player: Entity,
+    pub enemy: Entity,
+    pub players_turn: bool,
 }
 
 // Simple in-window combat UI and outcome overlays using Sprites
@@ -62,12 +218,75 @@ pub struct OutcomeUi;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AttackResult { Hit, Miss }
 
+// Where a DamageEvent originated, so apply_damage can phrase its log line appropriately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageSource { Attack, Burn, Poison }
+
+// hp is only ever reduced inside apply_damage; every other system that deals damage (turn
+// resolution, status-effect ticks) hands it the amount instead of touching CombatStats directly.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub amount: i32,
+    pub kind: AttackResult,
+    pub source: DamageSource,
+    // Whether this hit crit; purely cosmetic (GameLog colors crits yellow) but kept on the event
+    // since apply_damage is the only place with both the final amount and a reason to color it.
+    pub crit: bool,
+}
+
+// Fired by apply_damage the moment a target's hp drops to 0 or below, once per death (guarded so
+// a second killing blow on an already-dying entity doesn't fire twice).
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DeathEvent {
+    pub entity: Entity,
+    pub is_player: bool,
+}
+
 #[derive(Resource, Default, Debug, Clone)]
 pub struct CombatLog {
     pub last_player: Option<AttackResult>,
     pub last_enemy: Option<AttackResult>,
-    // Latest console-style message to mirror in UI (e.g., "Player hits enemy for 12 (enemy hp 34)")
-    pub last_msg: Option<String>,
+}
+
+// Colors used for GameLog lines: crits pop in yellow, misses fade to grey, player hits stay
+// readable white, enemy hits read as a threat in red.
+pub const CRIT_COLOR: Color = Color::srgb(1.0, 0.85, 0.1);
+pub const MISS_COLOR: Color = Color::srgb(0.6, 0.6, 0.6);
+pub const PLAYER_HIT_COLOR: Color = Color::WHITE;
+pub const ENEMY_HIT_COLOR: Color = Color::srgb(0.9, 0.25, 0.25);
+
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub text: String,
+    pub color: Color,
+}
+
+// Scrolling blow-by-blow combat log: a bounded ring buffer so memory stays flat across a long
+// fight. Replaces the old single `CombatLog.last_msg` line, which lost history every turn.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct GameLog {
+    entries: std::collections::VecDeque<LogEntry>,
+}
+
+impl GameLog {
+    // How many lines update_combat_ui stacks on screen; oldest beyond this is dropped.
+    pub const CAPACITY: usize = 8;
+
+    pub fn push(&mut self, color: Color, text: impl Into<String>) {
+        self.entries.push_back(LogEntry { text: text.into(), color });
+        while self.entries.len() > Self::CAPACITY {
+            self.entries.pop_front();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &LogEntry> {
+        self.entries.iter()
+    }
 }
 
 #[derive(Component)]
@@ -76,9 +295,9 @@ pub struct CombatUiLeftText;  // Player column
 #[derive(Component)]
 pub struct CombatUiRightText; // Enemy column
 
-// Center bottom combat alerts (mirror console info! logs)
+// Holds this line's row within the stacked log block (0 = topmost/oldest, CAPACITY-1 = bottom/newest).
 #[derive(Component)]
-pub struct CombatUiLogText;
+pub struct CombatUiLogText(pub usize);
 
 // Add player stats based on the RON config
 pub fn sync_player_stats(
@@ -96,46 +315,138 @@ pub fn sync_player_stats(
             defense: 2,
             crit_chance: 0.15,
             evade_chance: 0.1,
+            damage_type: DamageType::Physical,
         };
         // If we know the character entry, use its attack_damage for the player as well
         if let Some(cfg) = config_opt {
             stats.attack = cfg.attack_damage.max(1.0).round() as i32;
         }
-        commands.entity(e).insert(stats);
+
+        // Abilities come from the RON config when present; otherwise fall back to a single
+        // basic strike built from these same stats, so characters without a configured loadout
+        // still fight exactly as before this system existed.
+        let weapons = config_opt
+            .map(|cfg| cfg.abilities.clone())
+            .filter(|abilities| !abilities.is_empty())
+            .unwrap_or_else(|| vec![default_strike(&stats)]);
+
+        let max_mana = config_opt.map(|c| c.max_mana.max(0.0).round() as i32).unwrap_or(20);
+        let progression = Progression { xp: 0, level: 1, mana: Pool { current: max_mana, max: max_mana }, mana_regen_accum: 0.0 };
+
+        commands.entity(e).insert((stats, Abilities::new(weapons), progression));
 
         info!("Player stats synced");
     }
 }
 
+fn default_strike(stats: &CombatStats) -> Weapon {
+    Weapon {
+        name: "Strike".to_string(),
+        base_damage: stats.attack,
+        crit_chance: stats.crit_chance,
+        hit_modifier: 0.0,
+        damage_type: DamageType::Physical,
+        cooldown_turns: 0,
+        cost: 0,
+    }
+}
+
+// Entered once per combat: consumes the pending (player, enemy) pair and installs the
+// ActiveCombat resource for the duration of GamePhase::Combat.
+pub fn start_combat(
+    mut commands: Commands,
+    mut pending: ResMut<PendingCombat>,
+    mut enemy_controllers: Query<&mut bevy_rapier2d::prelude::KinematicCharacterController, With<Enemy>>,
+) {
+    let Some(info) = pending.0.take() else { return; };
+    // The aggroed enemy's controller still carries its last chase_player translation from the
+    // final Exploring frame (chase_player's steering is Combat-gated, but the value itself
+    // persists like move_player's does); clear it here or the enemy keeps sliding through the
+    // turn-based fight.
+    if let Ok(mut controller) = enemy_controllers.get_mut(info.enemy) {
+        controller.translation = None;
+    }
+    commands.insert_resource(ActiveCombat {
+        player: info.player,
+        enemy: info.enemy,
+        players_turn: true,
+    });
+}
+
+// Leaving Combat (win, loss, or abort) always removes the resource so the next fight starts clean.
+pub fn end_combat(mut commands: Commands) {
+    commands.remove_resource::<ActiveCombat>();
+}
+
+// Force the player to a neutral idle pose whenever combat starts or an outcome overlay appears,
+// since move_player no longer runs outside of GamePhase::Exploring to do this itself. Also clears
+// any pending KinematicCharacterController translation, since rapier's physics step keeps running
+// regardless of GamePhase and would otherwise keep sliding the player with whatever move_player
+// last requested.
+pub fn force_player_idle(
+    mut query: Query<(
+        &mut crate::characters::animation::AnimationController,
+        &mut crate::characters::animation::AnimationState,
+        Option<&mut bevy_rapier2d::prelude::KinematicCharacterController>,
+    ), With<Player>>,
+) {
+    let Ok((mut controller, mut state, phys_controller)) = query.single_mut() else { return; };
+    state.is_moving = false;
+    if !state.is_jumping && !matches!(controller.current_animation, AnimationType::Death | AnimationType::Attack) {
+        controller.current_animation = AnimationType::Walk; // use Walk's idle frame as idle
+    }
+    if let Some(mut phys_controller) = phys_controller {
+        phys_controller.translation = None;
+    }
+}
+
 // Main combat driver: press Space/Enter to advance turns. Probability-based hit/damage.
+// Damage is never applied directly here; the resolved amount is handed off as a DamageEvent so
+// apply_damage is the single place hp actually changes (see that fn for why).
 pub fn combat_input_and_turns(
     input: Res<ButtonInput<KeyCode>>,
-    mut state: ResMut<CombatState>,
-    mut player_q: Query<&mut CombatStats, With<Player>>, // only stats are mutated here
-    mut enemy_q: Query<(&mut CombatStats, &GlobalTransform), (With<Enemy>, Without<Player>)>,
+    gamepads: Query<&Gamepad>,
+    input_config: Res<crate::characters::movement::InputConfig>,
+    mut active: ResMut<ActiveCombat>,
+    mut next_phase: ResMut<NextState<GamePhase>>,
+    mut commands: Commands,
+    mut player_q: Query<(&CombatStats, Option<&Chilled>, &mut Abilities, &mut Progression), With<Player>>,
+    enemy_q: Query<(&CombatStats, &GlobalTransform, Option<&Chilled>), (With<Enemy>, Without<Player>)>,
     mut anim_sets: ParamSet<(
         Query<(&GlobalTransform, &mut crate::characters::animation::AnimationController, &mut crate::characters::animation::AnimationState), With<Player>>,
         Query<(&GlobalTransform, &mut crate::characters::animation::AnimationController, &mut crate::characters::animation::AnimationState), (With<Enemy>, Without<Player>)>,
         Query<(&crate::characters::animation::AnimationController, &crate::characters::animation::AnimationTimer, &Sprite, &crate::characters::config::CharacterEntry), With<Player>>,
         Query<(&crate::characters::animation::AnimationController, &crate::characters::animation::AnimationTimer, &Sprite, &crate::characters::config::CharacterEntry), (With<Enemy>, Without<Player>)>,
     )>,
-
-    /*
-    mut outcome: ResMut<GameOutcome>,
-    mut enemy_tracker: ResMut<EnemyTracker>,
-    mut commands: Commands,
-     */
-
     mut clog: ResMut<CombatLog>,
+    mut log: ResMut<GameLog>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
     // Damage scaling to make combat resolve faster while keeping balance fair.
     // Slightly favor the player and slightly reduce enemy damage as requested.
     const PLAYER_DAMAGE_MULTIPLIER: f32 = 2.2; // +10% vs previous 2.0
     const ENEMY_DAMAGE_MULTIPLIER: f32 = 1.8;  // -10% vs previous 2.0
 
-    let Some(active) = state.active.as_mut() else { return; };
+    // Number keys switch the player's selected ability any time it's their turn, independent of
+    // whether Space/Enter is also pressed this frame.
+    if active.players_turn {
+        const SELECT_KEYS: [(KeyCode, usize); 4] = [
+            (KeyCode::Digit1, 0), (KeyCode::Digit2, 1), (KeyCode::Digit3, 2), (KeyCode::Digit4, 3),
+        ];
+        for (key, idx) in SELECT_KEYS {
+            if input.just_pressed(key) {
+                if let Ok((_, _, mut abilities, _)) = player_q.get_mut(active.player) {
+                    if idx < abilities.weapons.len() {
+                        abilities.selected = idx;
+                    }
+                }
+            }
+        }
+    }
 
-    let proceed = input.just_pressed(KeyCode::Space) || input.just_pressed(KeyCode::Enter);
+    let proceed = input.just_pressed(KeyCode::Space)
+        || input.just_pressed(KeyCode::Enter)
+        || crate::characters::movement::gamepad_just_pressed(&gamepads, input_config.combat_button);
     if !proceed { return; }
 
     // If an attack animation is currently running for either actor, wait until it finishes
@@ -167,14 +478,16 @@ pub fn combat_input_and_turns(
     if attack_in_progress { return; }
 
     // Retrieve entities
-    let mut p_stats = match player_q.get_mut(active.player) {
+    let (p_stats, p_chilled, mut p_abilities, mut progression) = match player_q.get_mut(active.player) {
         Ok(v) => v,
-        Err(_) => { state.active = None; return; }
+        Err(_) => { next_phase.set(GamePhase::Exploring); return; }
     };
-    let (mut e_stats, e_gtf) = match enemy_q.get_mut(active.enemy) {
+    let (e_stats, e_gtf, e_chilled) = match enemy_q.get(active.enemy) {
         Ok(v) => v,
-        Err(_) => { state.active = None; return; }
+        Err(_) => { next_phase.set(GamePhase::Exploring); return; }
     };
+    let p_chilled = p_chilled.is_some();
+    let e_chilled = e_chilled.is_some();
 
     use rand::Rng;
     let mut rng = rand::rng();
@@ -182,55 +495,119 @@ pub fn combat_input_and_turns(
 
     // Turn resolution
     if active.players_turn {
-        // Player attacks enemy
-        let hit_chance: f32 = (0.85f32 - e_stats.evade_chance).clamp(0.1, 0.95);
-        if rand01() < hit_chance {
-            // Base damage with crit, then apply player-specific multiplier
-            // max clamps damage at 1 to avoid 0 damage
-            let mut dmg_f = ((p_stats.attack - e_stats.defense).max(1)) as f32;
-            if rand01() < p_stats.crit_chance { dmg_f *= 2.0; }
-            let dmg = (dmg_f * PLAYER_DAMAGE_MULTIPLIER).round().max(1.0) as i32;
-            e_stats.hp -= dmg;
-
-            info!("Player hits enemy for {} (enemy hp {})", dmg, e_stats.hp);
+        // Cooldowns count down once per player turn, then the ability actually used below is
+        // immediately refreshed to its own cooldown_turns.
+        p_abilities.tick_cooldowns();
+
+        // Fall back to the first ready *and affordable* ability if the selected one is on
+        // cooldown or the player can't pay its mana cost, so a stale selection doesn't just
+        // stall the turn.
+        if !p_abilities.can_use(p_abilities.selected, progression.mana.current) {
+            if let Some(idx) = (0..p_abilities.weapons.len()).find(|&i| p_abilities.can_use(i, progression.mana.current)) {
+                p_abilities.selected = idx;
+            }
+        }
+        let weapon = p_abilities.weapons[p_abilities.selected].clone();
+        // Recheck cooldown *and* cost on the ability the fallback above actually settled on —
+        // the fallback only searches for a ready-and-affordable ability when the prior selection
+        // fails can_use; it leaves `selected` untouched (and thus still on cooldown) when no such
+        // ability exists, so this must not collapse back to a mana-only check.
+        let can_act = p_abilities.can_use(p_abilities.selected, progression.mana.current);
+
+        // Cooldowns count down once per player turn, after this turn's readiness check, then the
+        // ability actually used below is refreshed to its own cooldown_turns.
+        p_abilities.tick_cooldowns();
+
+        // Player attacks enemy with whichever ability is selected; elemental weapons bypass
+        // defense and apply the matching status effect, same as enemy attacks do. If every
+        // ability (including the fallback above) is still on cooldown or unaffordable, the turn
+        // fizzles instead of landing a free hit.
+        if can_act {
+            p_abilities.use_ability(p_abilities.selected);
+            progression.mana.current -= weapon.cost;
+
+            let mut hit_chance: f32 = (0.85f32 + weapon.hit_modifier - e_stats.evade_chance).clamp(0.1, 0.95);
+            if p_chilled { hit_chance *= 0.5; }
+            if rand01() < hit_chance {
+                let base = match weapon.damage_type {
+                    DamageType::Physical => (weapon.base_damage - e_stats.defense).max(1),
+                    _ => weapon.base_damage.max(1),
+                };
+                let mut dmg_f = base as f32;
+                let crit = rand01() < weapon.crit_chance;
+                if crit { dmg_f *= 2.0; }
+                let dmg = (dmg_f * PLAYER_DAMAGE_MULTIPLIER).round().max(1.0) as i32;
+                damage_events.write(DamageEvent { target: active.enemy, amount: dmg, kind: AttackResult::Hit, source: DamageSource::Attack, crit });
+
+                clog.last_player = Some(AttackResult::Hit);
+
+                match weapon.damage_type {
+                    DamageType::Fire => {
+                        commands.entity(active.enemy).insert(Burn { dmg_per_turn: (dmg / 3).max(1), turns_left: 3 });
+                    }
+                    DamageType::Poison => {
+                        commands.entity(active.enemy).insert(Poison { dmg_per_turn: (dmg / 4).max(1), turns_left: 4 });
+                    }
+                    DamageType::Ice => {
+                        commands.entity(active.enemy).insert(Chilled { turns_left: 2 });
+                    }
+                    DamageType::Physical => {}
+                }
+            } else {
+                info!("Player missed!");
 
-            clog.last_player = Some(AttackResult::Hit);
-            clog.last_msg = Some(format!("Player hits enemy for {} (enemy hp {})", dmg, e_stats.hp));
+                clog.last_player = Some(AttackResult::Miss);
+                log.push(MISS_COLOR, "Player missed!");
+            }
+            // Trigger player attack animation facing the enemy
+            if let Ok((p_gtf, mut controller, mut astate)) = anim_sets.p0().get_mut(active.player) {
+                let dir = (e_gtf.translation().truncate() - p_gtf.translation().truncate()).normalize_or_zero();
+                controller.facing = Facing::from_direction(dir);
+                controller.current_animation = AnimationType::Attack;
+                astate.is_moving = false;
+            }
         } else {
-            info!("Player missed!");
-
+            info!("Player can't use {}!", weapon.name);
             clog.last_player = Some(AttackResult::Miss);
-            clog.last_msg = Some("Player missed!".to_string());
-        }
-        // Trigger player attack animation facing the enemy
-        if let Ok((p_gtf, mut controller, mut astate)) = anim_sets.p0().get_mut(active.player) {
-            let dir = (e_gtf.translation().truncate() - p_gtf.translation().truncate()).normalize_or_zero();
-            controller.facing = Facing::from_direction(dir);
-            controller.current_animation = AnimationType::Attack;
-            astate.is_moving = false;
+            log.push(MISS_COLOR, format!("Player can't use {}!", weapon.name));
         }
 
     } else {
-        // Enemy attacks player
-        let hit_chance: f32 = (0.75f32 - p_stats.evade_chance).clamp(0.1, 0.95);
+        // Enemy attacks player, using whichever DamageType its CombatStats carries
+        let mut hit_chance: f32 = (0.75f32 - p_stats.evade_chance).clamp(0.1, 0.95);
+        if e_chilled { hit_chance *= 0.5; }
         if rand01() < hit_chance {
-            // Base damage with crit, then apply enemy-specific multiplier
-            // max clamps damage at 1 to avoid 0 damage
-            let mut dmg_f = ((e_stats.attack - p_stats.defense).max(1)) as f32;
-            if rand01() < e_stats.crit_chance { dmg_f *= 2.0; }
+            // Physical damage is reduced by defense; elemental damage bypasses it entirely.
+            let base = match e_stats.damage_type {
+                DamageType::Physical => (e_stats.attack - p_stats.defense).max(1),
+                _ => e_stats.attack.max(1),
+            };
+            let mut dmg_f = base as f32;
+            let crit = rand01() < e_stats.crit_chance;
+            if crit { dmg_f *= 2.0; }
             let dmg = (dmg_f * ENEMY_DAMAGE_MULTIPLIER).round().max(1.0) as i32;
-            p_stats.hp -= dmg;
-
-
-            info!("Enemy hits player for {} (player hp {})", dmg, p_stats.hp);
+            damage_events.write(DamageEvent { target: active.player, amount: dmg, kind: AttackResult::Hit, source: DamageSource::Attack, crit });
 
             clog.last_enemy = Some(AttackResult::Hit);
-            clog.last_msg = Some(format!("Enemy hits player for {} (player hp {})", dmg, p_stats.hp));
+
+            // Elemental hits also leave a lingering status effect on the player
+            match e_stats.damage_type {
+                DamageType::Fire => {
+                    commands.entity(active.player).insert(Burn { dmg_per_turn: (dmg / 3).max(1), turns_left: 3 });
+                }
+                DamageType::Poison => {
+                    commands.entity(active.player).insert(Poison { dmg_per_turn: (dmg / 4).max(1), turns_left: 4 });
+                }
+                DamageType::Ice => {
+                    commands.entity(active.player).insert(Chilled { turns_left: 2 });
+                }
+                DamageType::Physical => {}
+            }
         } else {
             info!("Enemy missed!");
 
             clog.last_enemy = Some(AttackResult::Miss);
-            clog.last_msg = Some("Enemy missed!".to_string());
+            log.push(MISS_COLOR, "Enemy missed!");
         }
         // Trigger enemy attack animation facing the player
         // Borrow the player query first to read the position, then drop it before borrowing enemy mutably.
@@ -246,42 +623,124 @@ pub fn combat_input_and_turns(
         }
     }
 
-    // Check outcomes
-    if e_stats.hp <= 0 {
-        // Trigger enemy death animation and end combat; cleanup will occur after animation finishes
-        if let Ok((_, mut ctrl, mut astate)) = anim_sets.p1().get_mut(active.enemy) {
-            ctrl.current_animation = AnimationType::Death;
-            astate.is_moving = false;
+    // Outcomes (death, phase transition) are no longer decided here: apply_damage resolves the
+    // DamageEvents queued above and emits a DeathEvent if either combatant's hp drops to 0,
+    // which handle_death_events reacts to.
+    active.players_turn = !active.players_turn;
+}
+
+// Ticks Burn/Poison/Chilled on both combatants once per resolved turn. Rides on ActiveCombat's
+// change detection: combat_input_and_turns only flips `players_turn` when a turn actually
+// resolves, so `active.is_changed()` here fires exactly once per turn rather than every frame.
+// Damage is handed off as DamageEvents rather than applied directly; apply_damage (scheduled
+// right after this system) is what actually moves hp and notices deaths.
+pub fn tick_status_effects(
+    active: Res<ActiveCombat>,
+    mut commands: Commands,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut targets: Query<(Option<&mut Burn>, Option<&mut Poison>, Option<&mut Chilled>)>,
+) {
+    if !active.is_changed() { return; }
+
+    for entity in [active.player, active.enemy] {
+        let Ok((burn, poison, chilled)) = targets.get_mut(entity) else { continue; };
+
+        if let Some(mut burn) = burn {
+            damage_events.write(DamageEvent { target: entity, amount: burn.dmg_per_turn, kind: AttackResult::Hit, source: DamageSource::Burn, crit: false });
+            burn.turns_left = burn.turns_left.saturating_sub(1);
+            if burn.turns_left == 0 { commands.entity(entity).remove::<Burn>(); }
+        }
+
+        if let Some(mut poison) = poison {
+            damage_events.write(DamageEvent { target: entity, amount: poison.dmg_per_turn, kind: AttackResult::Hit, source: DamageSource::Poison, crit: false });
+            poison.turns_left = poison.turns_left.saturating_sub(1);
+            if poison.turns_left == 0 { commands.entity(entity).remove::<Poison>(); }
+        }
+
+        if let Some(mut chilled) = chilled {
+            chilled.turns_left = chilled.turns_left.saturating_sub(1);
+            if chilled.turns_left == 0 { commands.entity(entity).remove::<Chilled>(); }
         }
-        state.active = None;
-        return;
     }
+}
 
-    if p_stats.hp <= 0 {
-        // Trigger player death animation and end combat; outcome will be shown after animation finishes
-        if let Ok((_p_gtf, mut ctrl, mut astate)) = anim_sets.p0().get_mut(active.player) {
-            ctrl.current_animation = AnimationType::Death;
-            astate.is_moving = false;
+// The only system that ever subtracts from CombatStats.hp. Every attacker (combat_input_and_turns,
+// tick_status_effects) hands off a resolved amount instead of touching hp itself, so this is the
+// one place that has to stay correct about clamping, logging, and noticing deaths.
+pub fn apply_damage(
+    mut events: EventReader<DamageEvent>,
+    mut stats_q: Query<(&mut CombatStats, Option<&Player>)>,
+    mut anim_q: Query<(&mut crate::characters::animation::AnimationController, &mut crate::characters::animation::AnimationState)>,
+    mut log: ResMut<GameLog>,
+    mut death_events: EventWriter<DeathEvent>,
+) {
+    for ev in events.read() {
+        let Ok((mut stats, is_player)) = stats_q.get_mut(ev.target) else { continue; };
+        if stats.hp <= 0 { continue; } // already dead; ignore late-arriving ticks this turn
+
+        stats.hp -= ev.amount;
+        let label = if is_player.is_some() { "Player" } else { "Enemy" };
+        let msg = match ev.source {
+            DamageSource::Attack if is_player.is_some() => format!("Enemy hits player for {} (player hp {})", ev.amount, stats.hp),
+            DamageSource::Attack => format!("Player hits enemy for {} (enemy hp {})", ev.amount, stats.hp),
+            DamageSource::Burn => format!("{} burns for {} (hp {})", label, ev.amount, stats.hp),
+            DamageSource::Poison => format!("{} is poisoned for {} (hp {})", label, ev.amount, stats.hp),
+        };
+        info!("{}", msg);
+        let color = if ev.crit {
+            CRIT_COLOR
+        } else if matches!(ev.source, DamageSource::Attack) {
+            if is_player.is_some() { ENEMY_HIT_COLOR } else { PLAYER_HIT_COLOR }
+        } else {
+            // Status-effect ticks read as a threat regardless of who they're hitting.
+            ENEMY_HIT_COLOR
+        };
+        log.push(color, msg);
+
+        if stats.hp <= 0 {
+            // Trigger the Death animation here; handle_death_events only flips GamePhase, and
+            // handle_enemy_death_cleanup/handle_player_death_outcome wait for the clip to finish.
+            if let Ok((mut ctrl, mut astate)) = anim_q.get_mut(ev.target) {
+                ctrl.current_animation = AnimationType::Death;
+                astate.is_moving = false;
+            }
+            death_events.write(DeathEvent { entity: ev.target, is_player: is_player.is_some() });
         }
-        state.active = None;
-        return;
     }
+}
 
-    // Switch turns
-    active.players_turn = !active.players_turn;
+// Ends the current fight as soon as either combatant dies; which outcome overlay (if any) follows
+// is decided later, once the Death animation finishes (see handle_enemy_death_cleanup /
+// handle_player_death_outcome).
+pub fn handle_death_events(
+    mut events: EventReader<DeathEvent>,
+    mut next_phase: ResMut<NextState<GamePhase>>,
+) {
+    let mut any = false;
+    for _ in events.read() { any = true; }
+    if any {
+        next_phase.set(GamePhase::Exploring);
+    }
 }
 
-// Despawn enemies only after their Death animation is finished and update outcome if needed
+// Despawn enemies only after their Death animation is finished and update outcome if needed.
+// Only relevant once combat has ended (Exploring), so state-scope it there instead of re-checking
+// GameOutcome every frame.
 pub fn handle_enemy_death_cleanup(
     mut commands: Commands,
     mut enemy_tracker: ResMut<EnemyTracker>,
-    mut outcome: ResMut<GameOutcome>,
-    query: Query<(Entity, &crate::characters::animation::AnimationController, &crate::characters::animation::AnimationTimer, &Sprite, &crate::characters::config::CharacterEntry), With<Enemy>>,
+    mut next_phase: ResMut<NextState<GamePhase>>,
+    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    asset_server: Res<AssetServer>,
+    effects_handle: Option<Res<crate::characters::effects::EffectsRegistryHandle>>,
+    effects_registries: Res<Assets<crate::characters::effects::EffectsRegistry>>,
+    mut player_q: Query<(&mut Progression, &mut CombatStats), With<Player>>,
+    query: Query<(Entity, &GlobalTransform, &crate::characters::animation::AnimationController, &crate::characters::animation::AnimationTimer, &Sprite, &crate::characters::config::CharacterEntry), With<Enemy>>,
 ) {
     use crate::characters::config::AnimationType;
     use crate::characters::animation::AnimationClip;
 
-    for (entity, controller, timer, sprite, config) in query.iter() {
+    for (entity, g_tf, controller, timer, sprite, config) in query.iter() {
         if !matches!(controller.current_animation, AnimationType::Death) { continue; }
         let Some(atlas) = sprite.texture_atlas.as_ref() else { continue; };
         let Some(def) = config.animations.get(&AnimationType::Death) else { continue; };
@@ -291,21 +750,62 @@ pub fn handle_enemy_death_cleanup(
             // Death finished: despawn and adjust counts
             commands.entity(entity).despawn();
             if enemy_tracker.alive > 0 { enemy_tracker.alive -= 1; }
+            if let Ok((mut progression, mut stats)) = player_q.single_mut() {
+                progression.xp += xp_reward(config);
+                apply_level_ups(&mut progression, &mut stats);
+            }
+
+            // Death explosion, scaled to match this enemy's sprite scale. "large explosion" is
+            // reserved for bigger enemy types once those exist; every enemy uses the small one.
+            let registry = effects_handle.as_ref().and_then(|h| effects_registries.get(&h.0));
+            crate::characters::effects::spawn_named_effect(
+                &mut commands,
+                registry,
+                &asset_server,
+                &mut atlas_layouts,
+                "small explosion",
+                g_tf.translation(),
+                crate::characters::npc::ENEMY_SCALE,
+                Vec2::ZERO,
+            );
+
             if enemy_tracker.alive == 0 {
-                *outcome = GameOutcome::GameWon;
+                next_phase.set(GamePhase::Won);
             }
         }
     }
 }
 
-// After player Death animation completes, show Game Over
+// Passive mana regen while exploring, scaled by the player's CharacterEntry.mana_regen so caster
+// archetypes recover faster than fighters.
+pub fn regen_player_mana(
+    time: Res<Time>,
+    mut query: Query<(&mut Progression, &CharacterEntry), With<Player>>,
+) {
+    let Ok((mut progression, config)) = query.single_mut() else { return; };
+    if progression.mana.current >= progression.mana.max {
+        progression.mana_regen_accum = 0.0;
+        return;
+    }
+    // Accrue fractional regen in a float and only credit whole points to mana.current, so a slow
+    // regen rate (e.g. the default 2.0/sec against a 60fps delta_secs) still adds up instead of
+    // rounding away to nothing every frame.
+    progression.mana_regen_accum += config.mana_regen * time.delta_secs();
+    let gained = progression.mana_regen_accum.floor();
+    if gained >= 1.0 {
+        progression.mana_regen_accum -= gained;
+        progression.mana.current = (progression.mana.current + gained as i32).min(progression.mana.max);
+    }
+}
+
+// After player Death animation completes, show Game Over. Also state-scoped to Exploring so it
+// naturally stops once the GameOver/Won overlay takes over.
 pub fn handle_player_death_outcome(
-    mut outcome: ResMut<GameOutcome>,
+    mut next_phase: ResMut<NextState<GamePhase>>,
     query: Query<(&crate::characters::animation::AnimationController, &crate::characters::animation::AnimationTimer, &Sprite, &crate::characters::config::CharacterEntry), With<Player>>,
 ) {
     use crate::characters::config::AnimationType;
     use crate::characters::animation::AnimationClip;
-    if !matches!(*outcome, GameOutcome::None) { return; }
 
     let Ok((controller, timer, sprite, config)) = query.single() else { return; };
 
@@ -317,27 +817,26 @@ pub fn handle_player_death_outcome(
     let clip = AnimationClip::new(row, def.frame_count, config.atlas_columns);
 
     if clip.is_complete(atlas.index, timer.0.is_finished()) {
-        *outcome = GameOutcome::GameOver;
+        next_phase.set(GamePhase::GameOver);
     }
 }
 
 // Spawn a simple overlay text when combat starts
-pub fn spawn_combat_ui_on_start(
+pub fn spawn_combat_ui(
     mut commands: Commands,
-    state: Res<CombatState>,
-    existing: Query<Entity, With<CombatUiRoot>>,
+    active: Res<ActiveCombat>,
     cam_q: Query<&Transform, With<Camera2d>>,
     mut clog: ResMut<CombatLog>,
+    mut log: ResMut<GameLog>,
 ) {
-    if existing.iter().next().is_some() { return; }
-    let Some(_active) = state.active.as_ref() else { return; };
+    let _ = &active; // entities are only needed once turns start resolving
     let cam_pos = cam_q.single().map(|t| t.translation).unwrap_or(Vec3::ZERO);
     let base = Vec3::new(cam_pos.x, cam_pos.y - 220.0, 60.0);
 
     // Reset combat log on spawn
     clog.last_player = None;
     clog.last_enemy = None;
-    clog.last_msg = None;
+    log.clear();
 
     // Root marker
     commands.spawn((Transform::from_translation(base), CombatUiRoot));
@@ -364,176 +863,203 @@ pub fn spawn_combat_ui_on_start(
         CombatUiRightText,
     ));
 
-    // Center column: latest combat alert mirroring console log
-    commands.spawn((
-        Text2d::new("-"),
-        TextFont { font_size: 18.0, ..Default::default() },
-        TextColor(Color::WHITE),
-        TextLayout { justify: Justify::Center, ..Default::default() },
-        Transform::from_translation(base + Vec3::new(0.0, -24.0, 0.1)),
-        CombatUiLogText,
-    ));
+    // Scrolling combat log: one Text2d per row, stacked downward so the newest line lands at
+    // the bottom. Rows start blank and get filled in (bottom-up) as GameLog accumulates entries.
+    for row in 0..GameLog::CAPACITY {
+        commands.spawn((
+            Text2d::new(""),
+            TextFont { font_size: 16.0, ..Default::default() },
+            TextColor(Color::WHITE),
+            TextLayout { justify: Justify::Center, ..Default::default() },
+            Transform::from_translation(base + Vec3::new(0.0, -24.0 - row as f32 * 18.0, 0.1)),
+            CombatUiLogText(row),
+        ));
+    }
 }
 
 // Update the combat UI each frame: position and text
 pub fn update_combat_ui(
-    state: Res<CombatState>,
+    active: Res<ActiveCombat>,
     cam_q: Query<&Transform, With<Camera2d>>,
     clog: Res<CombatLog>,
+    log: Res<GameLog>,
+    abilities_q: Query<&Abilities, With<Player>>,
     mut texts: Query<(
         &mut Transform,
         &mut Text2d,
+        &mut TextColor,
         Option<&CombatUiLeftText>,
         Option<&CombatUiRightText>,
         Option<&CombatUiLogText>,
     ), Without<Camera2d>>,
 ) {
-    if state.active.is_none() { return; }
     let Ok(cam) = cam_q.single() else { return; };
     let cam_pos = cam.translation;
     let base = Vec3::new(cam_pos.x, cam_pos.y - 220.0, 60.0);
 
-    for (mut tf, mut text, is_left, is_right, is_log) in texts.iter_mut() {
+    // Only the rows at the tail of the block hold an entry; earlier rows stay blank until the
+    // log fills up.
+    let entries: Vec<&LogEntry> = log.iter().collect();
+    let first_filled_row = GameLog::CAPACITY - entries.len();
+
+    for (mut tf, mut text, mut color, is_left, is_right, log_row) in texts.iter_mut() {
         if is_left.is_some() {
             tf.translation = base + Vec3::new(-250.0, 0.0, 0.1);
-            let turn = if state.active.as_ref().map(|a| a.players_turn).unwrap_or(false) { "Player Turn" } else { "" };
+            let turn = if active.players_turn { "Player Turn" } else { "" };
             let last = match clog.last_player {
                 Some(AttackResult::Hit) => "Hit",
                 Some(AttackResult::Miss) => "Miss",
                 None => "-" };
-            text.0 = format!("{}\nLast: {}", turn, last);
+            let abilities_lines = abilities_q.single().ok().map(|abilities| {
+                abilities.weapons.iter().enumerate().map(|(i, weapon)| {
+                    let marker = if i == abilities.selected { ">" } else { " " };
+                    let state = if abilities.is_ready(i) { "Rdy".to_string() } else { format!("CD{}", abilities.cooldown_remaining(i)) };
+                    format!("{}{}:{} [{}]", marker, i + 1, weapon.name, state)
+                }).collect::<Vec<_>>().join("\n")
+            }).unwrap_or_default();
+            text.0 = format!("{}\nLast: {}\n{}", turn, last, abilities_lines);
 
         } else if is_right.is_some() {
             tf.translation = base + Vec3::new(250.0, 0.0, 0.1);
-            let turn = if state.active.as_ref().map(|a| a.players_turn).unwrap_or(true) { "" } else { "Enemy Turn" };
+            let turn = if active.players_turn { "" } else { "Enemy Turn" };
             let last = match clog.last_enemy {
                 Some(AttackResult::Hit) => "Hit",
                 Some(AttackResult::Miss) => "Miss",
                 None => "-" };
             text.0 = format!("{}\nLast: {}", turn, last);
 
-        } else if is_log.is_some() {
-            tf.translation = base + Vec3::new(0.0, -24.0, 0.1);
-            let msg = clog.last_msg.as_deref().unwrap_or("-");
-            text.0 = msg.to_string();
+        } else if let Some(CombatUiLogText(row)) = log_row {
+            tf.translation = base + Vec3::new(0.0, -24.0 - *row as f32 * 18.0, 0.1);
+            if *row < first_filled_row {
+                text.0.clear();
+                continue;
+            }
+            let entry = entries[*row - first_filled_row];
+            text.0 = entry.text.clone();
+            // Fade older lines toward the top of the block; the newest (bottom) stays full alpha.
+            let age_from_newest = entries.len() - 1 - (*row - first_filled_row);
+            let alpha = (1.0 - age_from_newest as f32 * 0.12).max(0.3);
+            color.0 = entry.color.with_alpha(alpha);
         }
     }
 }
 
 // Cleanup UI when combat ends
-pub fn cleanup_combat_ui_on_end(
-    state: Res<CombatState>,
+pub fn cleanup_combat_ui(
     mut commands: Commands,
+    mut clog: ResMut<CombatLog>,
+    mut log: ResMut<GameLog>,
     roots: Query<Entity, With<CombatUiRoot>>,
     p_bars: Query<Entity, With<PlayerHpBar>>,
     e_bars: Query<Entity, With<EnemyHpBar>>,
     lefts: Query<Entity, With<CombatUiLeftText>>,
     rights: Query<Entity, With<CombatUiRightText>>,
     logs: Query<Entity, With<CombatUiLogText>>,
-    mut clog: ResMut<CombatLog>,
-) {
-    if state.is_changed() && state.active.is_none() {
-        for e in roots.iter() { commands.entity(e).despawn(); }
-        for e in p_bars.iter() { commands.entity(e).despawn(); }
-        for e in e_bars.iter() { commands.entity(e).despawn(); }
-        for e in lefts.iter() { commands.entity(e).despawn(); }
-        for e in rights.iter() { commands.entity(e).despawn(); }
-        for e in logs.iter() { commands.entity(e).despawn(); }
-        // reset log
-        clog.last_player = None;
-        clog.last_enemy = None;
-        clog.last_msg = None;
-    }
-}
-
-// Show Game Over / Game Won overlay and handle restart
-pub fn show_outcome_overlay(
-    outcome: Res<GameOutcome>,
-    mut commands: Commands,
-    existing: Query<Entity, With<OutcomeUi>>,
-    cam_q: Query<&Transform, With<Camera2d>>,
 ) {
-    if outcome.is_changed() {
-        // clear existing
-        for e in existing.iter() { commands.entity(e).despawn(); }
-        if !matches!(*outcome, GameOutcome::None) {
-            // Full-screen tinted overlay sprite
-            let color = match *outcome {
-                GameOutcome::GameOver => Color::srgb(0.6, 0.0, 0.0).with_alpha(0.6),
-                GameOutcome::GameWon => Color::srgb(0.0, 0.6, 0.0).with_alpha(0.6),
-                GameOutcome::None => Color::BLACK
-            };
+    for e in roots.iter() { commands.entity(e).despawn(); }
+    for e in p_bars.iter() { commands.entity(e).despawn(); }
+    for e in e_bars.iter() { commands.entity(e).despawn(); }
+    for e in lefts.iter() { commands.entity(e).despawn(); }
+    for e in rights.iter() { commands.entity(e).despawn(); }
+    for e in logs.iter() { commands.entity(e).despawn(); }
+    // reset log
+    clog.last_player = None;
+    clog.last_enemy = None;
+    log.clear();
+}
 
-            let cam_pos = cam_q.single().map(|t| t.translation).unwrap_or(Vec3::ZERO);
-            commands.spawn((
-                Sprite { color, custom_size: Some(Vec2::new(2000.0, 2000.0)), ..default() },
-                // Keep overlay above other UI and sprites but within camera range
-                Transform::from_translation(Vec3::new(cam_pos.x, cam_pos.y, 70.0)),
-                OutcomeUi,
-            ));
-
-            // Centered text label for outcome
-            let (label, color) = match *outcome {
-                GameOutcome::GameOver => ("GAME OVER", Color::WHITE),
-                GameOutcome::GameWon => ("YOU WIN", Color::WHITE),
-                GameOutcome::None => ("", Color::WHITE),
-            };
-            commands.spawn((
-                Text2d::new(label),
-                TextFont { font_size: 64.0, ..Default::default() },
-                TextColor(color),
-                TextLayout { justify: Justify::Center, ..Default::default() },
-                Transform::from_translation(Vec3::new(cam_pos.x, cam_pos.y, 71.0)),
-                OutcomeUi,
-            ));
-        }
+fn outcome_overlay_contents(phase: GamePhase) -> (Color, &'static str) {
+    match phase {
+        GamePhase::GameOver => (Color::srgb(0.6, 0.0, 0.0).with_alpha(0.6), "GAME OVER"),
+        GamePhase::Won => (Color::srgb(0.0, 0.6, 0.0).with_alpha(0.6), "YOU WIN"),
+        _ => (Color::BLACK.with_alpha(0.0), ""),
     }
 }
 
+// Show the Game Over overlay (spawned once on OnEnter(GamePhase::GameOver))
+pub fn spawn_game_over_overlay(commands: Commands, cam_q: Query<&Transform, With<Camera2d>>) {
+    spawn_outcome_overlay(commands, cam_q, GamePhase::GameOver);
+}
+
+// Show the You Win overlay (spawned once on OnEnter(GamePhase::Won))
+pub fn spawn_won_overlay(commands: Commands, cam_q: Query<&Transform, With<Camera2d>>) {
+    spawn_outcome_overlay(commands, cam_q, GamePhase::Won);
+}
+
+fn spawn_outcome_overlay(mut commands: Commands, cam_q: Query<&Transform, With<Camera2d>>, phase: GamePhase) {
+    let (color, label) = outcome_overlay_contents(phase);
+    let cam_pos = cam_q.single().map(|t| t.translation).unwrap_or(Vec3::ZERO);
+
+    // Full-screen tinted overlay sprite
+    commands.spawn((
+        Sprite { color, custom_size: Some(Vec2::new(2000.0, 2000.0)), ..default() },
+        // Keep overlay above other UI and sprites but within camera range
+        Transform::from_translation(Vec3::new(cam_pos.x, cam_pos.y, 70.0)),
+        OutcomeUi,
+    ));
+
+    // Centered text label for outcome
+    commands.spawn((
+        Text2d::new(label),
+        TextFont { font_size: 64.0, ..Default::default() },
+        TextColor(Color::WHITE),
+        TextLayout { justify: Justify::Center, ..Default::default() },
+        Transform::from_translation(Vec3::new(cam_pos.x, cam_pos.y, 71.0)),
+        OutcomeUi,
+    ));
+}
+
+// Despawn whichever outcome overlay is on screen (registered for OnExit of both GameOver and Won)
+pub fn despawn_outcome_overlay(mut commands: Commands, existing: Query<Entity, With<OutcomeUi>>) {
+    for e in existing.iter() { commands.entity(e).despawn(); }
+}
+
 pub fn handle_restart_input(
     input: Res<ButtonInput<KeyCode>>,
-    mut state: ResMut<CombatState>,
-    mut outcome: ResMut<GameOutcome>,
+    phase: Res<State<GamePhase>>,
+    mut next_phase: ResMut<NextState<GamePhase>>,
     mut enemy_tracker: ResMut<EnemyTracker>,
+    mut difficulty: ResMut<crate::characters::npc::Difficulty>,
     mut commands: Commands,
     enemies_q: Query<Entity, With<Enemy>>,
-    mut player_q: Query<(&mut CombatStats, &mut Transform, &mut crate::characters::animation::AnimationController, &mut crate::characters::animation::AnimationState), With<Player>>,
+    mut player_q: Query<(&mut CombatStats, &mut Transform, &mut crate::characters::animation::AnimationController, &mut crate::characters::animation::AnimationState, &mut Abilities), With<Player>>,
     health_pips_q: Query<Entity, With<crate::characters::health::HealthPip>>,
     mut pip_tracker: ResMut<crate::characters::health::HealthPipTracker>,
+    mut log: ResMut<GameLog>,
 ) {
     // Allow restarting with R at any time
-    // let on_outcome = !matches!(*outcome, GameOutcome::None);
     let restart_pressed = input.just_pressed(KeyCode::KeyR);
-        // add back to restart_pressed if we want to restart on space or enter
-        // || (on_outcome && (input.just_pressed(KeyCode::Space) || input.just_pressed(KeyCode::Enter)));
 
     if restart_pressed {
-        // End any active combat session immediately
-        state.active = None;
-
+        log.clear();
         // Despawn any existing enemies
         for e in enemies_q.iter() {
             commands.entity(e).despawn();
         }
         // Reset trackers so enemies will respawn
-        enemy_tracker.spawned = false;
-        enemy_tracker.alive = 0;
+        enemy_tracker.reset_for_restart();
+        difficulty.elapsed_secs = 0.0;
 
         // Restore player
-        if let Ok((mut stats, mut tf, mut ctrl, mut astate)) = player_q.single_mut() {
+        if let Ok((mut stats, mut tf, mut ctrl, mut astate, mut abilities)) = player_q.single_mut() {
             stats.hp = stats.max_hp;
             tf.translation.x = 0.0;
             tf.translation.y = 0.0;
             // Reset animation to a neutral state so we are no longer stuck in Death
             ctrl.current_animation = crate::characters::config::AnimationType::Walk;
             astate.is_moving = false;
+            abilities.selected = 0;
+            abilities.reset_cooldowns();
         }
 
         // Clear health pips and reset tracker so they respawn
         for e in health_pips_q.iter() { commands.entity(e).despawn(); }
         pip_tracker.spawned = false;
 
-        *outcome = GameOutcome::None;
+        // Only actually change phase if we're not already exploring (avoids a spurious OnEnter)
+        if *phase.get() != GamePhase::Exploring {
+            next_phase.set(GamePhase::Exploring);
+        }
     }
 }
 
@@ -545,4 +1071,4 @@ pub fn handle_quit_input(
         // Fallback quit: immediately terminate the process
         std::process::exit(0);
     }
-}
\ No newline at end of file
+}
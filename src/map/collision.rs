@@ -1,6 +1,12 @@
 // custom collision system
+//
+// Tiles get a static rapier body/collider alongside their NonWalkable/Water marker at spawn time,
+// so player/enemy kinematic character controllers (see characters::movement,
+// characters::pathfinding) sweep against the physics world instead of every mover manually
+// scanning these markers' GlobalTransforms per sub-step.
 
 use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
 use crate::map::generate::TILE_SIZE;
 
 /// Component to mark tiles that should block player movement
@@ -9,7 +15,11 @@ pub struct NonWalkable;
 
 /// function to insert NonWalkable component during asset spawn
 pub fn insert_blocking(ec: &mut EntityCommands) {
-    ec.insert(NonWalkable);
+    ec.insert((
+        NonWalkable,
+        RigidBody::Fixed,
+        Collider::cuboid(nonwalkable_half_extent(), nonwalkable_half_extent()),
+    ));
 }
 
 /// Component for water tiles that should block the player from entering the water,
@@ -19,7 +29,13 @@ pub struct Water;
 
 /// function to insert Water during asset spawn
 pub fn insert_water_blocking(ec: &mut EntityCommands) {
-    ec.insert(Water);
+    // Collider is sized from water_half_extent(), which is already slightly smaller than a full
+    // tile (see below), so edges on grass aren't blocked by an exact tile-boundary overlap.
+    ec.insert((
+        Water,
+        RigidBody::Fixed,
+        Collider::cuboid(water_half_extent(), water_half_extent()),
+    ));
 }
 
 /// Returns the half-extent used for NonWalkable (water) collision AABBs.
@@ -7,4 +7,7 @@ pub mod sockets;
 pub mod generate;
 
 // added collision system
-pub mod collision;
\ No newline at end of file
+pub mod collision;
+
+// added spatial grid index so per-point tile collision is O(1) instead of scanning every tile
+pub mod spatial;
\ No newline at end of file
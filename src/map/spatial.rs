@@ -0,0 +1,200 @@
+// Spatial grid index for O(1) tile collision queries
+//
+// NonWalkable/Water collision checks used to scan every blocking tile's GlobalTransform per
+// candidate point (see characters::npc::spawn_enemies_once, characters::health::spawn_health_pips_once,
+// and characters::pathfinding::build_walk_grid). With a full tilemap that's thousands of
+// iterations per candidate. This resource buckets each tile by its grid cell once after terrain
+// spawn, so a point query becomes a single hash lookup plus an AABB check against that cell's
+// half-extent instead of a linear scan.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::map::collision::{nonwalkable_half_extent, water_half_extent, NonWalkable, Water};
+use crate::map::generate::TILE_SIZE;
+
+fn world_to_cell(point: Vec2) -> IVec2 {
+    IVec2::new((point.x / TILE_SIZE).floor() as i32, (point.y / TILE_SIZE).floor() as i32)
+}
+
+fn cell_center(cell: IVec2) -> Vec2 {
+    Vec2::new((cell.x as f32 + 0.5) * TILE_SIZE, (cell.y as f32 + 0.5) * TILE_SIZE)
+}
+
+/// Collision footprint size in tiles (e.g. a 2x2 boss), declared per CharacterEntry. Most
+/// characters default to 1x1, which behaves exactly like a single-point check.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TileSize {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Default for TileSize {
+    fn default() -> Self {
+        Self { width: 1, height: 1 }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct TileBlock {
+    pub solid: bool,
+    pub water: bool,
+}
+
+/// Per-cell tile lookup, populated once from NonWalkable/Water tiles after terrain spawn.
+#[derive(Resource, Default)]
+pub struct SpatialIndex {
+    cells: HashMap<IVec2, TileBlock>,
+    built: bool,
+}
+
+impl SpatialIndex {
+    pub fn is_built(&self) -> bool {
+        self.built
+    }
+
+    /// True if `point` falls inside a NonWalkable tile's AABB.
+    pub fn is_solid(&self, point: Vec2) -> bool {
+        let cell = world_to_cell(point);
+        let Some(block) = self.cells.get(&cell) else { return false; };
+        if !block.solid {
+            return false;
+        }
+        let center = cell_center(cell);
+        let half = nonwalkable_half_extent();
+        (point.x - center.x).abs() <= half && (point.y - center.y).abs() <= half
+    }
+
+    /// True if `point` falls inside a Water tile's AABB.
+    pub fn is_water(&self, point: Vec2) -> bool {
+        let cell = world_to_cell(point);
+        let Some(block) = self.cells.get(&cell) else { return false; };
+        if !block.water {
+            return false;
+        }
+        let center = cell_center(cell);
+        let half = water_half_extent();
+        (point.x - center.x).abs() < half && (point.y - center.y).abs() < half
+    }
+
+    /// True if `point` falls inside either a solid or water tile's AABB.
+    pub fn is_blocked(&self, point: Vec2) -> bool {
+        self.is_solid(point) || self.is_water(point)
+    }
+
+    /// True if any cell under a `footprint`-sized box centered at `center` carries a solid or
+    /// water tile. Used for multi-tile characters/enemies, where a single point check at the
+    /// center would miss obstacles under the rest of their footprint. Any overlapped cell that
+    /// carries a tile counts as blocked, without `is_blocked`'s per-tile AABB check, and a 1x1 box
+    /// that is not aligned to the grid overlaps up to four cells, so this is stricter than
+    /// `is_blocked` at the same point.
+    pub fn is_blocked_footprint(&self, center: Vec2, footprint: TileSize) -> bool {
+        let half = Vec2::new(footprint.width as f32, footprint.height as f32) * TILE_SIZE * 0.5;
+        let min_cell = world_to_cell(center - half + Vec2::splat(0.01));
+        let max_cell = world_to_cell(center + half - Vec2::splat(0.01));
+        for x in min_cell.x..=max_cell.x {
+            for y in min_cell.y..=max_cell.y {
+                let block = self.cells.get(&IVec2::new(x, y)).copied().unwrap_or_default();
+                if block.solid || block.water {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Every cell that carries a solid and/or water tile, for consumers (pathfinding's WalkGrid)
+    /// that want the whole blocked set rather than point queries.
+    pub fn blocked_cells(&self) -> impl Iterator<Item = IVec2> + '_ {
+        self.cells.iter().filter(|(_, block)| block.solid || block.water).map(|(cell, _)| *cell)
+    }
+
+    /// Forces a rebuild on the next `build_spatial_index` call, for when terrain regenerates.
+    pub fn mark_dirty(&mut self) {
+        self.cells.clear();
+        self.built = false;
+    }
+
+    fn cell_is_solid(&self, cell: IVec2) -> bool {
+        self.cells.get(&cell).is_some_and(|block| block.solid)
+    }
+
+    /// Bresenham line walk from `from` to `to` (world positions) over the tile grid, checking
+    /// every intermediate cell for a NonWalkable tile. Both endpoints are skipped so standing
+    /// right next to a wall doesn't block sight. Returns false the moment a solid cell is hit
+    /// before reaching `to`.
+    pub fn line_of_sight(&self, from: Vec2, to: Vec2) -> bool {
+        let start = world_to_cell(from);
+        let end = world_to_cell(to);
+
+        let dx = (end.x - start.x).abs();
+        let dy = -(end.y - start.y).abs();
+        let sx: i32 = if start.x < end.x { 1 } else { -1 };
+        let sy: i32 = if start.y < end.y { 1 } else { -1 };
+        let mut err = dx + dy;
+        let (mut x, mut y) = (start.x, start.y);
+
+        loop {
+            if (x, y) == (end.x, end.y) {
+                return true;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+            if (x, y) == (end.x, end.y) {
+                return true;
+            }
+            if self.cell_is_solid(IVec2::new(x, y)) {
+                return false;
+            }
+        }
+    }
+}
+
+/// How many Update ticks to wait with zero NonWalkable/Water tiles before treating the map as a
+/// legitimately obstacle-free one rather than "generation hasn't spawned tiles yet". Map
+/// generation runs in Startup, whose spawn commands are flushed before the first Update tick, so
+/// this is just headroom against ordering ambiguity, not a wait for a slow multi-frame generator.
+const BUILD_GRACE_TICKS: u32 = 2;
+
+/// Populate `SpatialIndex` from the tiles spawned by map generation. No-ops once built; call
+/// `SpatialIndex::mark_dirty` after regenerating terrain to force a rebuild.
+pub fn build_spatial_index(
+    mut index: ResMut<SpatialIndex>,
+    mut ticks_waited: Local<u32>,
+    blocking_tiles: Query<&GlobalTransform, With<NonWalkable>>,
+    water_tiles: Query<&GlobalTransform, With<Water>>,
+) {
+    if index.built {
+        return;
+    }
+
+    if blocking_tiles.is_empty() && water_tiles.is_empty() {
+        // Could mean terrain hasn't spawned yet, or a legitimately obstacle-free map. Wait a
+        // couple of ticks to cover the former, then mark the index built (empty) rather than
+        // stalling every system gated on `is_built()` forever (spawn_enemies_once,
+        // spawn_health_pips_once, pathfinding::build_walk_grid).
+        *ticks_waited += 1;
+        if *ticks_waited < BUILD_GRACE_TICKS {
+            return;
+        }
+        index.built = true;
+        return;
+    }
+
+    for gt in blocking_tiles.iter() {
+        index.cells.entry(world_to_cell(gt.translation().truncate())).or_default().solid = true;
+    }
+    for gt in water_tiles.iter() {
+        index.cells.entry(world_to_cell(gt.translation().truncate())).or_default().water = true;
+    }
+    index.built = true;
+}